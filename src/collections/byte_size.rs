@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use arrow::array::{Array, ArrayDataRef, StructArray};
+use arrow::buffer::Buffer;
+
+/// Counts the bytes of the buffers of collections, where each buffer is only counted once.
+///
+/// Collections share buffers if they are slices or chunks of the same collection. Adding them
+/// to the same counter thus counts their shared memory once. Buffers are identified by their
+/// memory address and kept alive by the counter, so that a dropped buffer cannot free an address
+/// for another buffer that would then not be counted.
+#[derive(Clone, Debug, Default)]
+pub struct ByteSizeCounter {
+    counted_buffers: HashMap<usize, Buffer>,
+    byte_size: usize,
+}
+
+impl ByteSizeCounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of bytes of all counted buffers
+    pub fn byte_size(&self) -> usize {
+        self.byte_size
+    }
+
+    /// Counts the buffers of all columns of `data` and returns the number of bytes of those that were not counted before
+    pub(super) fn count_struct_array(&mut self, data: &StructArray) -> usize {
+        self.count_array_data(data.data_ref())
+    }
+
+    fn count_array_data(&mut self, data: &ArrayDataRef) -> usize {
+        let mut byte_size = data
+            .null_buffer()
+            .map_or(0, |buffer| self.count_buffer(buffer));
+
+        for buffer in data.buffers() {
+            byte_size += self.count_buffer(buffer);
+        }
+
+        for child_data in data.child_data() {
+            byte_size += self.count_array_data(child_data);
+        }
+
+        byte_size
+    }
+
+    fn count_buffer(&mut self, buffer: &Buffer) -> usize {
+        let address = buffer.raw_data() as usize;
+
+        if buffer.is_empty() || self.counted_buffers.contains_key(&address) {
+            return 0;
+        }
+
+        self.counted_buffers.insert(address, buffer.clone());
+
+        self.byte_size += buffer.len();
+        buffer.len()
+    }
+}
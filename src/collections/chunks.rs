@@ -0,0 +1,110 @@
+use std::mem;
+
+use snafu::ensure;
+
+use crate::collections::{helpers, FeatureCollection};
+use crate::error;
+use crate::primitives::FeatureData;
+use crate::util::Result;
+
+/// The limit for the size of each chunk of a collection
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChunkLimit {
+    /// The maximum number of features of a chunk
+    Features(usize),
+    /// The maximum number of bytes of the feature values of a chunk.
+    /// A feature that exceeds the limit on its own forms a chunk of its own.
+    Bytes(usize),
+}
+
+/// Splits features of the sizes `feature_sizes` into consecutive chunks of `(offset, len)`.
+///
+/// Sizes are only computed for `ChunkLimit::Bytes`.
+pub(super) fn chunk_bounds<F>(
+    len: usize,
+    limit: ChunkLimit,
+    feature_sizes: F,
+) -> Result<Vec<(usize, usize)>>
+where
+    F: FnOnce() -> Result<Vec<usize>>,
+{
+    let mut bounds = Vec::new();
+
+    match limit {
+        ChunkLimit::Features(max_features) => {
+            ensure!(
+                max_features > 0,
+                error::FeatureCollection {
+                    details: "A chunk must contain at least one feature",
+                }
+            );
+
+            for offset in (0..len).step_by(max_features) {
+                bounds.push((offset, max_features.min(len - offset)));
+            }
+        }
+        ChunkLimit::Bytes(max_bytes) => {
+            let mut offset = 0;
+            let mut chunk_bytes = 0;
+
+            for (row, size) in feature_sizes()?.into_iter().enumerate() {
+                if row > offset && chunk_bytes + size > max_bytes {
+                    bounds.push((offset, row - offset));
+                    offset = row;
+                    chunk_bytes = 0;
+                }
+                chunk_bytes += size;
+            }
+
+            if offset < len {
+                bounds.push((offset, len - offset));
+            }
+        }
+    }
+
+    Ok(bounds)
+}
+
+/// The bytes of the time interval and attribute values of each feature of `collection`
+pub(super) fn feature_value_byte_sizes<C: FeatureCollection>(collection: &C) -> Result<Vec<usize>> {
+    let mut sizes = vec![2 * mem::size_of::<i64>(); collection.len()];
+
+    for column in collection.column_types().keys() {
+        let data = helpers::feature_data_from_ref(&collection.data(column)?, collection.len())?;
+
+        let fixed_size = match &data {
+            FeatureData::Text(values) => {
+                add_text_sizes(&mut sizes, values.iter().map(String::len));
+                continue;
+            }
+            FeatureData::NullableText(values) => {
+                add_text_sizes(
+                    &mut sizes,
+                    values
+                        .iter()
+                        .map(|value| value.as_ref().map_or(0, String::len)),
+                );
+                continue;
+            }
+            FeatureData::Number(_) | FeatureData::NullableNumber(_) => mem::size_of::<f64>(),
+            FeatureData::Decimal(_) | FeatureData::NullableDecimal(_) => mem::size_of::<i64>(),
+            FeatureData::Categorical(_)
+            | FeatureData::NullableCategorical(_)
+            | FeatureData::Bool(_)
+            | FeatureData::NullableBool(_) => mem::size_of::<u8>(),
+        };
+
+        for size in &mut sizes {
+            *size += fixed_size;
+        }
+    }
+
+    Ok(sizes)
+}
+
+/// Adds the bytes of strings of the lengths `text_lengths` and their offsets to `sizes`
+fn add_text_sizes(sizes: &mut [usize], text_lengths: impl Iterator<Item = usize>) {
+    for (size, text_length) in sizes.iter_mut().zip(text_lengths) {
+        *size += text_length + mem::size_of::<i32>();
+    }
+}
@@ -0,0 +1,373 @@
+use std::io::{Read, Write};
+
+use csv::{ReaderBuilder, StringRecord, Writer};
+use snafu::ensure;
+
+use crate::error;
+use crate::primitives::{
+    FeatureDataRef, FeatureDataType, FeatureDataValue, NullableDataRef, TimeInterval,
+};
+use crate::util::Result;
+
+/// The columns of a CSV file that hold the coordinates and time intervals of point features
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CsvColumns {
+    pub x: String,
+    pub y: String,
+    pub time_start: Option<String>,
+    pub time_end: Option<String>,
+}
+
+impl CsvColumns {
+    /// Create a column specification for coordinates without time columns
+    pub fn new(x: &str, y: &str) -> Self {
+        Self {
+            x: x.to_string(),
+            y: y.to_string(),
+            time_start: None,
+            time_end: None,
+        }
+    }
+
+    /// Add columns for the start and end of time intervals in ms
+    pub fn with_time(mut self, time_start: Option<&str>, time_end: Option<&str>) -> Self {
+        self.time_start = time_start.map(str::to_string);
+        self.time_end = time_end.map(str::to_string);
+        self
+    }
+
+    fn is_reserved(&self, column: &str) -> bool {
+        column == self.x
+            || column == self.y
+            || self.time_start.as_deref() == Some(column)
+            || self.time_end.as_deref() == Some(column)
+    }
+}
+
+/// The parsed rows of a CSV file with point features
+pub(super) struct CsvPoints {
+    pub coordinates: Vec<(f64, f64)>,
+    pub time_intervals: Vec<TimeInterval>,
+    pub columns: Vec<(String, FeatureDataType)>,
+    column_indices: Vec<usize>,
+    records: Vec<StringRecord>,
+}
+
+impl CsvPoints {
+    /// Retrieve the value of the `column_index`th data column of a row
+    pub fn value(&self, row: usize, column_index: usize) -> Result<FeatureDataValue> {
+        let record = &self.records[row];
+        let index = self.column_indices[column_index];
+        let cell = &record[index];
+
+        let parse_error = |details: &str| error::Error::CsvParse {
+            row: line(record),
+            column: index + 1,
+            details: details.to_string(),
+        };
+
+        Ok(match self.columns[column_index].1 {
+            FeatureDataType::Text => FeatureDataValue::Text(cell.to_string()),
+            FeatureDataType::NullableText => {
+                FeatureDataValue::NullableText(non_empty(cell).map(str::to_string))
+            }
+            FeatureDataType::Number => FeatureDataValue::Number(
+                cell.parse()
+                    .map_err(|_| parse_error("Cell is not a number"))?,
+            ),
+            FeatureDataType::NullableNumber => FeatureDataValue::NullableNumber(
+                non_empty(cell)
+                    .map(str::parse)
+                    .transpose()
+                    .map_err(|_| parse_error("Cell is not a number"))?,
+            ),
+            FeatureDataType::Decimal => FeatureDataValue::Decimal(
+                cell.parse()
+                    .map_err(|_| parse_error("Cell is not a decimal"))?,
+            ),
+            FeatureDataType::NullableDecimal => FeatureDataValue::NullableDecimal(
+                non_empty(cell)
+                    .map(str::parse)
+                    .transpose()
+                    .map_err(|_| parse_error("Cell is not a decimal"))?,
+            ),
+            FeatureDataType::Categorical
+            | FeatureDataType::NullableCategorical
+            | FeatureDataType::Bool
+            | FeatureDataType::NullableBool => {
+                unreachable!("categories and bools are never inferred from CSV")
+            }
+        })
+    }
+}
+
+/// Reads point features from CSV with a header row.
+///
+/// Coordinates must be numbers and time columns integers in ms, where empty time cells denote unbounded intervals.
+/// All other columns become `Decimal`s if all cells are integers, `Number`s if all cells are numbers and
+/// `Text`s otherwise. If there are empty cells, the nullable variant is used.
+pub(super) fn read_points<R: Read>(reader: R, columns: &CsvColumns) -> Result<CsvPoints> {
+    let mut reader = ReaderBuilder::new().has_headers(true).from_reader(reader);
+
+    let headers = reader.headers().map_err(csv_error)?.clone();
+    let records = reader
+        .records()
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(csv_error)?;
+
+    let x_index = column_position(&headers, &columns.x)?;
+    let y_index = column_position(&headers, &columns.y)?;
+    let time_start_index = columns
+        .time_start
+        .as_ref()
+        .map(|column| column_position(&headers, column))
+        .transpose()?;
+    let time_end_index = columns
+        .time_end
+        .as_ref()
+        .map(|column| column_position(&headers, column))
+        .transpose()?;
+
+    let mut coordinates = Vec::with_capacity(records.len());
+    let mut time_intervals = Vec::with_capacity(records.len());
+
+    for record in &records {
+        coordinates.push((
+            parse_cell::<f64>(record, x_index, "Coordinate is not a number")?,
+            parse_cell::<f64>(record, y_index, "Coordinate is not a number")?,
+        ));
+
+        let default = TimeInterval::default();
+        let start = time_start_index
+            .map(|index| parse_optional_cell::<i64>(record, index, "Time is not an integer"))
+            .transpose()?
+            .flatten()
+            .unwrap_or_else(|| default.start());
+        let end = time_end_index
+            .map(|index| parse_optional_cell::<i64>(record, index, "Time is not an integer"))
+            .transpose()?
+            .flatten()
+            .unwrap_or_else(|| default.end());
+
+        time_intervals.push(TimeInterval::new(start, end).map_err(|error| {
+            error::Error::CsvParse {
+                row: line(record),
+                column: time_start_index.or(time_end_index).unwrap_or_default() + 1,
+                details: error.to_string(),
+            }
+        })?);
+    }
+
+    let (column_indices, data_columns): (Vec<usize>, Vec<(String, FeatureDataType)>) = headers
+        .iter()
+        .enumerate()
+        .filter(|(_, column)| !columns.is_reserved(column))
+        .map(|(index, column)| {
+            (
+                index,
+                (column.to_string(), infer_data_type(&records, index)),
+            )
+        })
+        .unzip();
+
+    Ok(CsvPoints {
+        coordinates,
+        time_intervals,
+        columns: data_columns,
+        column_indices,
+        records,
+    })
+}
+
+fn infer_data_type(records: &[StringRecord], index: usize) -> FeatureDataType {
+    let mut nullable = false;
+    let mut decimal = true;
+    let mut number = true;
+
+    for record in records {
+        match non_empty(&record[index]) {
+            None => nullable = true,
+            Some(cell) => {
+                decimal &= cell.parse::<i64>().is_ok();
+                number &= cell.parse::<f64>().is_ok();
+            }
+        }
+    }
+
+    match (decimal, number, nullable) {
+        // only empty cells are treated as text
+        _ if nullable && records.iter().all(|record| record[index].is_empty()) => {
+            FeatureDataType::NullableText
+        }
+        (true, _, false) => FeatureDataType::Decimal,
+        (true, _, true) => FeatureDataType::NullableDecimal,
+        (false, true, false) => FeatureDataType::Number,
+        (false, true, true) => FeatureDataType::NullableNumber,
+        (false, false, false) => FeatureDataType::Text,
+        (false, false, true) => FeatureDataType::NullableText,
+    }
+}
+
+fn column_position(headers: &StringRecord, column: &str) -> Result<usize> {
+    headers
+        .iter()
+        .position(|header| header == column)
+        .ok_or_else(|| error::Error::Csv {
+            details: format!("Column {} does not exist", column),
+        })
+}
+
+fn parse_cell<T: std::str::FromStr>(
+    record: &StringRecord,
+    index: usize,
+    details: &str,
+) -> Result<T> {
+    record[index].parse().map_err(|_| error::Error::CsvParse {
+        row: line(record),
+        column: index + 1,
+        details: details.to_string(),
+    })
+}
+
+fn parse_optional_cell<T: std::str::FromStr>(
+    record: &StringRecord,
+    index: usize,
+    details: &str,
+) -> Result<Option<T>> {
+    if record[index].is_empty() {
+        return Ok(None);
+    }
+    parse_cell(record, index, details).map(Some)
+}
+
+fn non_empty(cell: &str) -> Option<&str> {
+    if cell.is_empty() {
+        None
+    } else {
+        Some(cell)
+    }
+}
+
+/// The line of a record in the CSV file, starting at 1 for the header
+fn line(record: &StringRecord) -> u64 {
+    record.position().map_or(0, csv::Position::line)
+}
+
+fn csv_error(error: csv::Error) -> error::Error {
+    error::Error::Csv {
+        details: error.to_string(),
+    }
+}
+
+/// Writes point features as CSV with a header row, where null values become empty cells
+pub(super) fn write_points<W: Write>(
+    writer: W,
+    columns: &CsvColumns,
+    coordinates: &[(f64, f64)],
+    time_intervals: &[TimeInterval],
+    data_columns: &[(&str, Vec<String>)],
+) -> Result<()> {
+    ensure!(
+        data_columns
+            .iter()
+            .all(|(column, _)| !columns.is_reserved(column)),
+        error::Csv {
+            details: "Data columns must not be named like coordinate or time columns"
+        }
+    );
+
+    let mut writer = Writer::from_writer(writer);
+
+    let mut header = vec![columns.x.as_str(), columns.y.as_str()];
+    header.extend(columns.time_start.as_deref());
+    header.extend(columns.time_end.as_deref());
+    header.extend(data_columns.iter().map(|(column, _)| *column));
+    writer.write_record(&header).map_err(csv_error)?;
+
+    for (row, (&(x, y), time_interval)) in coordinates.iter().zip(time_intervals).enumerate() {
+        let mut record = vec![format!("{:?}", x), format!("{:?}", y)];
+        if columns.time_start.is_some() {
+            record.push(time_interval.start().to_string());
+        }
+        if columns.time_end.is_some() {
+            record.push(time_interval.end().to_string());
+        }
+        record.extend(data_columns.iter().map(|(_, values)| values[row].clone()));
+
+        writer.write_record(&record).map_err(csv_error)?;
+    }
+
+    writer.flush().map_err(|error| error::Error::Csv {
+        details: error.to_string(),
+    })
+}
+
+/// Converts a data column into CSV cells where nulls become empty cells.
+///
+/// Numbers are always written with a fractional part or exponent so that they are not read back as decimals.
+pub(super) fn csv_values(data: &FeatureDataRef, len: usize) -> Result<Vec<String>> {
+    fn with_nulls<T>(values: &[T], nulls: Vec<bool>, to_cell: fn(&T) -> String) -> Vec<String> {
+        values
+            .iter()
+            .zip(nulls)
+            .map(|(value, is_null)| {
+                if is_null {
+                    String::new()
+                } else {
+                    to_cell(value)
+                }
+            })
+            .collect()
+    }
+
+    fn number_cell(number: &f64) -> String {
+        format!("{:?}", number)
+    }
+
+    Ok(match data {
+        FeatureDataRef::Number(numbers) => {
+            numbers.as_ref()[..len].iter().map(number_cell).collect()
+        }
+        FeatureDataRef::NullableNumber(numbers) => {
+            with_nulls(&numbers.as_ref()[..len], numbers.nulls(), number_cell)
+        }
+        FeatureDataRef::Decimal(decimals) => decimals.as_ref()[..len]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        FeatureDataRef::NullableDecimal(decimals) => with_nulls(
+            &decimals.as_ref()[..len],
+            decimals.nulls(),
+            ToString::to_string,
+        ),
+        FeatureDataRef::Categorical(categories) => categories.as_ref()[..len]
+            .iter()
+            .map(ToString::to_string)
+            .collect(),
+        FeatureDataRef::NullableCategorical(categories) => with_nulls(
+            &categories.as_ref()[..len],
+            categories.nulls(),
+            ToString::to_string,
+        ),
+        FeatureDataRef::Text(texts) => (0..len)
+            .map(|i| texts.text_at(i).map(str::to_string))
+            .collect::<Result<_>>()?,
+        FeatureDataRef::NullableText(texts) => (0..len)
+            .map(|i| {
+                texts
+                    .text_at(i)
+                    .map(|text| text.map_or_else(String::new, str::to_string))
+            })
+            .collect::<Result<_>>()?,
+        FeatureDataRef::Bool(bools) => (0..len)
+            .map(|i| bools.value_at(i).map(|value| value.to_string()))
+            .collect::<Result<_>>()?,
+        FeatureDataRef::NullableBool(bools) => (0..len)
+            .map(|i| {
+                bools
+                    .value_at(i)
+                    .map(|value| value.map_or_else(String::new, |value| value.to_string()))
+            })
+            .collect::<Result<_>>()?,
+    })
+}
@@ -0,0 +1,608 @@
+use std::collections::HashMap;
+
+use arrow::array::{Array, ArrayBuilder, BooleanArray, StructArray, StructBuilder};
+use arrow::datatypes::{DataType, Field};
+use snafu::ensure;
+
+use crate::collections::{helpers, ByteSizeCounter, FeatureCollection};
+use crate::error;
+use crate::operations::{Filterable, Takeable};
+use crate::primitives::{FeatureData, FeatureDataRef, FeatureDataType, TimeInterval};
+use crate::util::Result;
+
+/// A feature collection without geometries, i.e., a table of time intervals and attributes
+#[derive(Debug)]
+pub struct DataCollection {
+    data: StructArray,
+    types: HashMap<String, FeatureDataType>,
+}
+
+impl Clone for DataCollection {
+    /// Clone the DataCollection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    ///
+    /// let dc = DataCollection::empty();
+    /// let cloned = dc.clone();
+    ///
+    /// assert_eq!(dc.len(), 0);
+    /// assert_eq!(cloned.len(), 0);
+    /// ```
+    ///
+    fn clone(&self) -> Self {
+        Self {
+            data: StructArray::from(self.data.data()),
+            types: self.types.clone(),
+        }
+    }
+}
+
+impl DataCollection {
+    /// Retrieve the composite arrow data type for time intervals
+    pub(self) fn time_data_type() -> DataType {
+        helpers::time_data_type()
+    }
+
+    /// Create an empty DataCollection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    ///
+    /// let dc = DataCollection::empty();
+    ///
+    /// assert_eq!(dc.len(), 0);
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            data: {
+                let columns = vec![Field::new(
+                    Self::TIME_COLUMN_NAME,
+                    Self::time_data_type(),
+                    false,
+                )];
+
+                helpers::empty_struct_array(columns)
+            },
+            types: Default::default(),
+        }
+    }
+
+    /// Create a data collection from time intervals and attribute data
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let dc = DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("temperature".into(), FeatureData::NullableNumber(vec![Some(21.5), None]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert_eq!(dc.len(), 2);
+    /// assert_eq!(dc.time_intervals(), &[TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)]);
+    ///
+    /// assert!(DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("__features".into(), FeatureData::Number(vec![0.]));
+    ///         map
+    ///     },
+    /// ).is_err());
+    /// ```
+    pub fn from_data(
+        time_intervals: Vec<TimeInterval>,
+        data: HashMap<String, FeatureData>,
+    ) -> Result<Self> {
+        let capacity = time_intervals.len();
+
+        let mut columns = vec![Field::new(
+            Self::TIME_COLUMN_NAME,
+            Self::time_data_type(),
+            false,
+        )];
+
+        let mut builders: Vec<Box<dyn ArrayBuilder>> =
+            vec![Box::new(helpers::time_intervals_builder(&time_intervals)?)];
+
+        let mut data_types = HashMap::with_capacity(data.len());
+
+        for (name, feature_data) in data {
+            ensure!(
+                !Self::is_reserved_name(&name),
+                error::ColumnNameConflict { name }
+            );
+
+            ensure!(
+                feature_data.len() == capacity,
+                error::FeatureCollection {
+                    details: format!(
+                        "Length of column {} must match the number of time intervals",
+                        name
+                    )
+                }
+            );
+
+            let column = Field::new(
+                &name,
+                feature_data.arrow_data_type(),
+                feature_data.nullable(),
+            );
+
+            columns.push(column);
+            builders.push(feature_data.arrow_builder()?);
+
+            data_types.insert(name, FeatureDataType::from(&feature_data));
+        }
+
+        if capacity == 0 {
+            return Ok(Self {
+                data: helpers::empty_struct_array(columns),
+                types: data_types,
+            });
+        }
+
+        let mut struct_builder = StructBuilder::new(columns, builders);
+        for _ in 0..capacity {
+            struct_builder.append(true)?;
+        }
+
+        Ok(Self {
+            data: struct_builder.finish(),
+            types: data_types,
+        })
+    }
+}
+
+impl FeatureCollection for DataCollection {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// A data collection is always simple since it contains no geometries
+    fn is_simple(&self) -> bool {
+        true
+    }
+
+    /// Retrieves a data column of this data collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let dc = DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("station".into(), FeatureData::Text(vec!["a".into(), "b".into()]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// if let FeatureDataRef::Text(stations) = dc.data("station").unwrap() {
+    ///     assert_eq!(stations.text_at(1).unwrap(), "b");
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(dc.data("__time").is_err());
+    /// ```
+    ///
+    fn data(&self, column_name: &str) -> Result<FeatureDataRef> {
+        ensure!(
+            !Self::is_reserved_name(column_name),
+            error::FeatureCollection {
+                details: "Cannot access reserved columns via `data()` method"
+            }
+        );
+
+        helpers::feature_data_ref(&self.data, &self.types, column_name)
+    }
+
+    fn time_intervals(&self) -> &[TimeInterval] {
+        helpers::time_intervals_slice(&self.data, Self::TIME_COLUMN_NAME)
+    }
+
+    fn column_types(&self) -> &HashMap<String, FeatureDataType> {
+        &self.types
+    }
+
+    fn count_byte_size(&self, counter: &mut ByteSizeCounter) -> usize {
+        counter.count_struct_array(&self.data)
+    }
+
+    /// Extend the collection by an additional column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let dc = DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let extended = dc.add_column("foo", FeatureData::Decimal(vec![1, 2])).unwrap();
+    ///
+    /// if let FeatureDataRef::Decimal(decimals) = extended.data("foo").unwrap() {
+    ///     assert_eq!(decimals.as_ref(), &[1, 2]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(extended.add_column("bar", FeatureData::Decimal(vec![1])).is_err());
+    /// assert!(extended.remove_column("foo").unwrap().data("foo").is_err());
+    /// ```
+    fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(new_column) && self.data.column_by_name(new_column).is_none(),
+            error::FeatureCollection {
+                details: "Cannot extend collection with name that is reserved or already in use"
+            }
+        );
+
+        ensure!(
+            data.len() == self.data.len(),
+            error::FeatureCollection {
+                details: "Length of new feature data column must match length of collection"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.insert(new_column.to_string(), FeatureDataType::from(&data));
+
+        Ok(Self {
+            data: helpers::struct_array_with_column(&self.data, new_column, &data)?,
+            types,
+        })
+    }
+
+    fn remove_column(&self, column: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+            error::FeatureCollection {
+                details: "Must not remove a non-existing or mandatory column"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.remove(column);
+
+        Ok(Self {
+            data: helpers::struct_array_without_column(&self.data, column),
+            types,
+        })
+    }
+
+    fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(old_name) && self.data.column_by_name(old_name).is_some(),
+            error::FeatureCollection {
+                details: "Must not rename a non-existing or mandatory column"
+            }
+        );
+        ensure!(
+            !Self::is_reserved_name(new_name) && self.data.column_by_name(new_name).is_none(),
+            error::ColumnNameConflict { name: new_name }
+        );
+
+        let mut types = self.types.clone();
+        if let Some(data_type) = types.remove(old_name) {
+            types.insert(new_name.to_string(), data_type);
+        }
+
+        Ok(Self {
+            data: helpers::struct_array_with_renamed_column(&self.data, old_name, new_name),
+            types,
+        })
+    }
+
+    fn select_columns(&self, columns: &[&str]) -> Result<Self> {
+        for &column in columns {
+            ensure!(
+                !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+                error::FeatureCollection {
+                    details: format!(
+                        "Cannot select the non-existing or mandatory column {}",
+                        column
+                    )
+                }
+            );
+        }
+
+        let types = self
+            .types
+            .iter()
+            .filter(|(column, _)| columns.contains(&column.as_str()))
+            .map(|(column, data_type)| (column.clone(), *data_type))
+            .collect();
+
+        Ok(Self {
+            data: helpers::struct_array_with_columns(&self.data, |column| {
+                Self::is_reserved_name(column) || columns.contains(&column)
+            }),
+            types,
+        })
+    }
+}
+
+impl Filterable for DataCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use geoengine_datatypes::operations::Filterable;
+    /// use std::collections::HashMap;
+    ///
+    /// let dc = DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::Number(vec![0., 1.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let filtered = dc.filter(vec![false, true]).unwrap();
+    ///
+    /// assert_eq!(filtered.len(), 1);
+    /// assert_eq!(filtered.time_intervals(), &[TimeInterval::new_unchecked(1, 2)]);
+    /// if let FeatureDataRef::Number(numbers) = filtered.data("foo").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[1.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    fn filter(&self, mask: Vec<bool>) -> Result<Self> {
+        ensure!(
+            mask.len() == self.data.len(),
+            error::MaskLengthDoesNotMatchCollectionLength {
+                mask_length: mask.len(),
+                collection_length: self.data.len(),
+            }
+        );
+
+        let filter_array: BooleanArray = mask.into();
+
+        Ok(Self {
+            data: helpers::filter_struct_array(
+                &self.data,
+                &filter_array,
+                Self::FEATURE_COLUMN_NAME,
+                |_features, _filter_array| unreachable!("data collections have no feature column"),
+                Self::TIME_COLUMN_NAME,
+            )?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+impl Takeable for DataCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use std::collections::HashMap;
+    ///
+    /// let dc = DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::Number(vec![0., 1.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let taken = dc.take(&[1, 1, 0]).unwrap();
+    ///
+    /// assert_eq!(taken.len(), 3);
+    /// if let FeatureDataRef::Number(numbers) = taken.data("foo").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[1., 1., 0.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// let sliced = dc.slice(1, 1).unwrap();
+    ///
+    /// assert_eq!(sliced.time_intervals(), &[TimeInterval::new_unchecked(1, 2)]);
+    /// ```
+    fn take(&self, indices: &[usize]) -> Result<Self> {
+        Ok(Self {
+            data: helpers::take_struct_array(&self.data, indices)?,
+            types: self.types.clone(),
+        })
+    }
+
+    fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        Ok(Self {
+            data: helpers::slice_struct_array(&self.data, offset, len)?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::primitives::NullableDataRef;
+
+    #[test]
+    fn clone() {
+        let dc = DataCollection::from_data(vec![TimeInterval::new_unchecked(0, 1)], {
+            let mut map = HashMap::new();
+            map.insert("number".into(), FeatureData::Number(vec![0.]));
+            map
+        })
+        .unwrap();
+
+        let cloned = dc.clone();
+
+        assert_eq!(dc.len(), cloned.len());
+        assert_eq!(dc.time_intervals(), cloned.time_intervals());
+    }
+
+    #[test]
+    fn has_no_feature_column() {
+        let dc = DataCollection::from_data(
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(dc
+            .data
+            .column_by_name(DataCollection::FEATURE_COLUMN_NAME)
+            .is_none());
+        assert_eq!(dc.data.num_columns(), 1);
+        assert!(dc.data("__features").is_err());
+    }
+
+    #[test]
+    fn from_data_errors() {
+        for (name, data) in &[
+            ("number", FeatureData::Number(vec![0.])),
+            ("number", FeatureData::Number(vec![0.; 3])),
+            ("__time", FeatureData::Number(vec![0.; 2])),
+            ("__features", FeatureData::Number(vec![0.; 2])),
+        ] {
+            let mut map = HashMap::new();
+            map.insert((*name).to_string(), data.clone());
+
+            assert!(DataCollection::from_data(vec![TimeInterval::default(); 2], map).is_err());
+        }
+    }
+
+    #[test]
+    fn empty_input() {
+        let dc = DataCollection::from_data(vec![], {
+            let mut map = HashMap::new();
+            map.insert("text".into(), FeatureData::Text(vec![]));
+            map
+        })
+        .unwrap();
+
+        assert!(dc.is_empty());
+        assert!(dc.time_intervals().is_empty());
+        assert!(dc.data("text").is_ok());
+        assert!(DataCollection::empty().time_intervals().is_empty());
+    }
+
+    #[test]
+    fn filter_attributes() {
+        let dc = DataCollection::from_data(vec![TimeInterval::default(); 3], {
+            let mut map = HashMap::new();
+            map.insert(
+                "number".into(),
+                FeatureData::NullableNumber(vec![Some(0.), None, Some(2.)]),
+            );
+            map.insert(
+                "text".into(),
+                FeatureData::Text(vec!["a".into(), "b".into(), "c".into()]),
+            );
+            map
+        })
+        .unwrap();
+
+        let filtered = dc.filter(vec![false, true, true]).unwrap();
+
+        assert_eq!(filtered.len(), 2);
+        if let FeatureDataRef::NullableNumber(numbers) = filtered.data("number").unwrap() {
+            assert_eq!(numbers.as_ref()[1], 2.);
+            assert_eq!(numbers.nulls(), vec![true, false]);
+        } else {
+            unreachable!();
+        }
+        if let FeatureDataRef::Text(texts) = filtered.data("text").unwrap() {
+            assert_eq!(texts.text_at(0).unwrap(), "b");
+            assert_eq!(texts.text_at(1).unwrap(), "c");
+        } else {
+            unreachable!();
+        }
+
+        assert!(dc.filter(vec![false; 3]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn take_and_slice_attributes() {
+        let dc = DataCollection::from_data(
+            (0..4)
+                .map(|i| TimeInterval::new_unchecked(i, i + 1))
+                .collect(),
+            {
+                let mut map = HashMap::new();
+                map.insert(
+                    "number".into(),
+                    FeatureData::NullableNumber(vec![Some(0.), None, Some(2.), None]),
+                );
+                map.insert(
+                    "text".into(),
+                    FeatureData::Text(vec!["a".into(), "b".into(), "c".into(), "d".into()]),
+                );
+                map
+            },
+        )
+        .unwrap();
+
+        let taken = dc.take(&[3, 0]).unwrap();
+
+        if let FeatureDataRef::NullableNumber(numbers) = taken.data("number").unwrap() {
+            assert_eq!(numbers.as_ref()[1], 0.);
+            assert_eq!(numbers.nulls(), vec![true, false]);
+        } else {
+            unreachable!();
+        }
+        if let FeatureDataRef::Text(texts) = taken.data("text").unwrap() {
+            assert_eq!(texts.text_at(0).unwrap(), "d");
+            assert_eq!(texts.text_at(1).unwrap(), "a");
+        } else {
+            unreachable!();
+        }
+
+        let sliced = dc.slice(1, 2).unwrap().slice(1, 1).unwrap();
+
+        assert_eq!(
+            sliced.time_intervals(),
+            &[TimeInterval::new_unchecked(2, 3)]
+        );
+        if let FeatureDataRef::NullableNumber(numbers) = sliced.data("number").unwrap() {
+            assert_eq!(numbers.as_ref(), &[2.]);
+            assert_eq!(numbers.nulls(), vec![false]);
+        } else {
+            unreachable!();
+        }
+        if let FeatureDataRef::Text(texts) = sliced.data("text").unwrap() {
+            assert_eq!(texts.text_at(0).unwrap(), "c");
+        } else {
+            unreachable!();
+        }
+
+        assert!(dc.take(&[4]).is_err());
+        assert!(dc.slice(3, 2).is_err());
+        assert!(dc.take(&[]).unwrap().is_empty());
+    }
+}
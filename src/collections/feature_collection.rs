@@ -1,4 +1,8 @@
-use crate::primitives::{FeatureData, FeatureDataRef, TimeInterval};
+use std::collections::HashMap;
+
+use crate::collections::ByteSizeCounter;
+use crate::operations::Filterable;
+use crate::primitives::{FeatureData, FeatureDataRef, FeatureDataType, TimeInterval};
 use crate::util::Result;
 
 /// This trait defines common features of all feature collections
@@ -31,6 +35,46 @@ pub trait FeatureCollection {
     /// Retrieve time intervals
     fn time_intervals(&self) -> &[TimeInterval];
 
+    /// Retrieve the names and types of the (non-reserved) columns
+    fn column_types(&self) -> &HashMap<String, FeatureDataType>;
+
+    /// Returns the number of bytes of the geometry, time, attribute and null bitmap buffers
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{ByteSizeCounter, MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::default(); 2],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// // coordinates, time intervals, multi-point offsets and null bitmaps
+    /// assert_eq!(pc.byte_size(), 3 * 16 + 2 * 16 + 3 * 4 + 3);
+    ///
+    /// // a slice shares the coordinates and time intervals with the collection
+    /// let slice = pc.slice(1, 1).unwrap();
+    /// let mut counter = ByteSizeCounter::new();
+    ///
+    /// assert_eq!(pc.count_byte_size(&mut counter), pc.byte_size());
+    /// // only the rebased multi-point offsets of the slice are new
+    /// assert_eq!(slice.count_byte_size(&mut counter), 2 * 4);
+    /// assert!(slice.byte_size() > 2 * 4);
+    /// assert_eq!(counter.byte_size(), pc.byte_size() + 2 * 4);
+    /// ```
+    fn byte_size(&self) -> usize {
+        self.count_byte_size(&mut ByteSizeCounter::new())
+    }
+
+    /// Counts the buffers of the collection with `counter` and returns the number of bytes of
+    /// those that were not counted before, i.e., that are not shared with previously counted collections
+    fn count_byte_size(&self, counter: &mut ByteSizeCounter) -> usize;
+
     /// Creates a copy of the collection with an additional column
     fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self>
     where
@@ -40,6 +84,77 @@ pub trait FeatureCollection {
     fn remove_column(&self, column: &str) -> Result<Self>
     where
         Self: Sized;
+
+    /// Renames a column and returns an updated collection
+    fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Keeps only the given (non-reserved) columns and returns an updated collection
+    fn select_columns(&self, columns: &[&str]) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Keeps the features whose time interval intersects `time_interval`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 5), TimeInterval::new_unchecked(5, 10), TimeInterval::new_unchecked(10, 15)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let filtered = pc.filter_time(&TimeInterval::new_unchecked(4, 10)).unwrap();
+    ///
+    /// assert_eq!(filtered.time_intervals(), &[TimeInterval::new_unchecked(0, 5), TimeInterval::new_unchecked(5, 10)]);
+    /// ```
+    fn filter_time(&self, time_interval: &TimeInterval) -> Result<Self>
+    where
+        Self: Filterable + Sized,
+    {
+        filter_time_by(self, |feature_time| time_interval.intersects(feature_time))
+    }
+
+    /// Keeps the features whose time interval is contained in `time_interval`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 5), TimeInterval::new_unchecked(5, 10), TimeInterval::new_unchecked(10, 15)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let filtered = pc.filter_time_contained(&TimeInterval::new_unchecked(4, 10)).unwrap();
+    ///
+    /// assert_eq!(filtered.time_intervals(), &[TimeInterval::new_unchecked(5, 10)]);
+    /// ```
+    fn filter_time_contained(&self, time_interval: &TimeInterval) -> Result<Self>
+    where
+        Self: Filterable + Sized,
+    {
+        filter_time_by(self, |feature_time| time_interval.contains(feature_time))
+    }
+}
+
+/// Keeps the features of `collection` whose time interval satisfies `predicate`
+fn filter_time_by<C, P>(collection: &C, predicate: P) -> Result<C>
+where
+    C: FeatureCollection + Filterable,
+    P: Fn(&TimeInterval) -> bool,
+{
+    collection.filter(collection.time_intervals().iter().map(predicate).collect())
 }
 
 #[cfg(test)]
@@ -61,12 +176,24 @@ mod test {
         fn time_intervals(&self) -> &[TimeInterval] {
             unimplemented!()
         }
+        fn column_types(&self) -> &HashMap<String, FeatureDataType> {
+            unimplemented!()
+        }
+        fn count_byte_size(&self, _counter: &mut ByteSizeCounter) -> usize {
+            unimplemented!()
+        }
         fn add_column(&self, _new_column: &str, _data: FeatureData) -> Result<Self> {
             unimplemented!()
         }
         fn remove_column(&self, _column: &str) -> Result<Self> {
             unimplemented!()
         }
+        fn rename_column(&self, _old_name: &str, _new_name: &str) -> Result<Self> {
+            unimplemented!()
+        }
+        fn select_columns(&self, _columns: &[&str]) -> Result<Self> {
+            unimplemented!()
+        }
     }
 
     #[test]
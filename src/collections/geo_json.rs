@@ -0,0 +1,406 @@
+use std::io::Write;
+
+use serde_json::{json, Map, Value};
+
+use snafu::ensure;
+
+use crate::error;
+use crate::primitives::{
+    Coordinate2D, FeatureDataRef, FeatureDataType, FeatureDataValue, NullableDataRef, TimeInterval,
+};
+use crate::util::Result;
+
+/// Writes a GeoJSON `FeatureCollection` feature by feature into `writer`.
+///
+/// Each feature gets its time interval as the `time_property` object with `start` and `end` in ms
+/// and all `columns` as further properties.
+pub(super) fn write_feature_collection<W, G>(
+    mut writer: W,
+    geometries: G,
+    time_intervals: &[TimeInterval],
+    time_property: &str,
+    columns: &[(&str, Vec<Value>)],
+) -> Result<()>
+where
+    W: Write,
+    G: Iterator<Item = Value>,
+{
+    write_bytes(&mut writer, br#"{"type":"FeatureCollection","features":["#)?;
+
+    for (feature_index, (geometry, time_interval)) in geometries.zip(time_intervals).enumerate() {
+        if feature_index > 0 {
+            write_bytes(&mut writer, b",")?;
+        }
+
+        let mut properties = Map::with_capacity(columns.len() + 1);
+        properties.insert(
+            time_property.to_string(),
+            json!({
+                "start": time_interval.start(),
+                "end": time_interval.end(),
+            }),
+        );
+        for (column, values) in columns {
+            properties.insert((*column).to_string(), values[feature_index].clone());
+        }
+
+        let feature = json!({
+            "type": "Feature",
+            "geometry": geometry,
+            "properties": properties,
+        });
+
+        serde_json::to_writer(&mut writer, &feature).map_err(|error| error::Error::GeoJson {
+            details: error.to_string(),
+        })?;
+    }
+
+    write_bytes(&mut writer, b"]}")
+}
+
+fn write_bytes<W: Write>(writer: &mut W, bytes: &[u8]) -> Result<()> {
+    writer
+        .write_all(bytes)
+        .map_err(|error| error::Error::GeoJson {
+            details: error.to_string(),
+        })
+}
+
+/// Converts a data column into JSON values where nulls become `null`
+pub(super) fn json_values(data: &FeatureDataRef, len: usize) -> Result<Vec<Value>> {
+    Ok(match data {
+        FeatureDataRef::Number(numbers) => numbers.as_ref()[..len]
+            .iter()
+            .map(|&number| Value::from(number))
+            .collect(),
+        FeatureDataRef::NullableNumber(numbers) => {
+            nullable_json_values(&numbers.as_ref()[..len], numbers.nulls())
+        }
+        FeatureDataRef::Decimal(decimals) => decimals.as_ref()[..len]
+            .iter()
+            .map(|&decimal| Value::from(decimal))
+            .collect(),
+        FeatureDataRef::NullableDecimal(decimals) => {
+            nullable_json_values(&decimals.as_ref()[..len], decimals.nulls())
+        }
+        FeatureDataRef::Categorical(categories) => categories.as_ref()[..len]
+            .iter()
+            .map(|&category| Value::from(category))
+            .collect(),
+        FeatureDataRef::NullableCategorical(categories) => {
+            nullable_json_values(&categories.as_ref()[..len], categories.nulls())
+        }
+        FeatureDataRef::Text(texts) => (0..len)
+            .map(|i| texts.text_at(i).map(Value::from))
+            .collect::<Result<_>>()?,
+        FeatureDataRef::NullableText(texts) => (0..len)
+            .map(|i| {
+                texts
+                    .text_at(i)
+                    .map(|text| text.map_or(Value::Null, Value::from))
+            })
+            .collect::<Result<_>>()?,
+        FeatureDataRef::Bool(bools) => (0..len)
+            .map(|i| bools.value_at(i).map(Value::from))
+            .collect::<Result<_>>()?,
+        FeatureDataRef::NullableBool(bools) => (0..len)
+            .map(|i| {
+                bools
+                    .value_at(i)
+                    .map(|value| value.map_or(Value::Null, Value::from))
+            })
+            .collect::<Result<_>>()?,
+    })
+}
+
+fn nullable_json_values<T>(values: &[T], nulls: Vec<bool>) -> Vec<Value>
+where
+    T: Copy + Into<Value>,
+{
+    values
+        .iter()
+        .zip(nulls)
+        .map(|(&value, is_null)| if is_null { Value::Null } else { value.into() })
+        .collect()
+}
+
+/// The properties that hold the time interval of GeoJSON features.
+///
+/// The property values must be integers that denote ms. If a property is not set or
+/// a feature lacks its value, the time interval is unbounded at this side.
+/// Without any configured property, the `__time` object of `write_geo_json` is used if present.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GeoJsonTimeProperties {
+    pub start: Option<String>,
+    pub end: Option<String>,
+}
+
+impl GeoJsonTimeProperties {
+    /// Create time properties for start and end
+    pub fn new(start: &str, end: &str) -> Self {
+        Self {
+            start: Some(start.to_string()),
+            end: Some(end.to_string()),
+        }
+    }
+
+    /// Checks whether a property is used for time and must not become a data column
+    fn is_time_property(&self, property: &str, time_property: &str) -> bool {
+        match (&self.start, &self.end) {
+            (None, None) => property == time_property,
+            (start, end) => start.as_deref() == Some(property) || end.as_deref() == Some(property),
+        }
+    }
+
+    /// Extracts the time interval from the properties of a feature
+    fn time_interval(
+        &self,
+        properties: &Map<String, Value>,
+        time_property: &str,
+    ) -> Result<TimeInterval> {
+        let default = TimeInterval::default();
+
+        if self.start.is_none() && self.end.is_none() {
+            return match properties.get(time_property) {
+                Some(Value::Object(time)) => TimeInterval::new(
+                    time_value(time.get("start"), "start")?.unwrap_or_else(|| default.start()),
+                    time_value(time.get("end"), "end")?.unwrap_or_else(|| default.end()),
+                ),
+                Some(Value::Null) | None => Ok(default),
+                Some(_) => error::GeoJson {
+                    details: format!("Property {} must be an object", time_property),
+                }
+                .fail(),
+            };
+        }
+
+        let start = match &self.start {
+            Some(property) => time_value(properties.get(property), property)?,
+            None => None,
+        };
+        let end = match &self.end {
+            Some(property) => time_value(properties.get(property), property)?,
+            None => None,
+        };
+
+        TimeInterval::new(
+            start.unwrap_or_else(|| default.start()),
+            end.unwrap_or_else(|| default.end()),
+        )
+    }
+}
+
+fn time_value(value: Option<&Value>, property: &str) -> Result<Option<i64>> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(value) => value
+            .as_i64()
+            .map(Some)
+            .ok_or_else(|| error::Error::GeoJson {
+                details: format!("Time property {} must be an integer", property),
+            }),
+    }
+}
+
+/// Parses a GeoJSON position, ignoring any further dimensions
+pub(super) fn coordinate(position: &Value) -> Result<Coordinate2D> {
+    match position.as_array().map(Vec::as_slice) {
+        Some([x, y, ..]) => match (x.as_f64(), y.as_f64()) {
+            (Some(x), Some(y)) => Ok(Coordinate2D::new(x, y)),
+            _ => error::GeoJson {
+                details: format!("Invalid position {}", position),
+            }
+            .fail(),
+        },
+        _ => error::GeoJson {
+            details: format!("Invalid position {}", position),
+        }
+        .fail(),
+    }
+}
+
+/// The geometries, time intervals and data columns of a parsed GeoJSON `FeatureCollection`
+pub(super) struct GeoJsonFeatures {
+    pub geometries: Vec<Value>,
+    pub time_intervals: Vec<TimeInterval>,
+    pub columns: Vec<(String, FeatureDataType)>,
+    pub properties: Vec<Map<String, Value>>,
+}
+
+impl GeoJsonFeatures {
+    /// Retrieve the value of a data column of a feature
+    pub fn value(
+        &self,
+        feature_index: usize,
+        column: &str,
+        data_type: FeatureDataType,
+    ) -> Result<FeatureDataValue> {
+        let value = self.properties[feature_index].get(column);
+
+        Ok(match (data_type, value) {
+            (FeatureDataType::Text, Some(Value::String(text))) => {
+                FeatureDataValue::Text(text.clone())
+            }
+            (FeatureDataType::NullableText, Some(Value::String(text))) => {
+                FeatureDataValue::NullableText(Some(text.clone()))
+            }
+            (FeatureDataType::NullableText, _) => FeatureDataValue::NullableText(None),
+            (FeatureDataType::Number, Some(Value::Number(number))) => {
+                FeatureDataValue::Number(number.as_f64().unwrap_or(f64::NAN))
+            }
+            (FeatureDataType::NullableNumber, Some(Value::Number(number))) => {
+                FeatureDataValue::NullableNumber(number.as_f64())
+            }
+            (FeatureDataType::NullableNumber, _) => FeatureDataValue::NullableNumber(None),
+            (FeatureDataType::Decimal, Some(Value::Number(number))) if number.is_i64() => {
+                FeatureDataValue::Decimal(number.as_i64().unwrap_or_default())
+            }
+            (FeatureDataType::NullableDecimal, Some(Value::Number(number))) if number.is_i64() => {
+                FeatureDataValue::NullableDecimal(number.as_i64())
+            }
+            (FeatureDataType::NullableDecimal, _) => FeatureDataValue::NullableDecimal(None),
+            (FeatureDataType::Bool, Some(&Value::Bool(value))) => FeatureDataValue::Bool(value),
+            (FeatureDataType::NullableBool, Some(&Value::Bool(value))) => {
+                FeatureDataValue::NullableBool(Some(value))
+            }
+            (FeatureDataType::NullableBool, _) => FeatureDataValue::NullableBool(None),
+            (data_type, value) => {
+                return error::GeoJson {
+                    details: format!(
+                        "Property {} has value {:?} that does not match type {:?}",
+                        column, value, data_type
+                    ),
+                }
+                .fail()
+            }
+        })
+    }
+}
+
+/// Parses a GeoJSON `FeatureCollection` and infers the data type of each property.
+///
+/// Properties with only integers become decimals, with any other numbers numbers, with strings texts and with booleans bools.
+/// If a property is missing or `null` for any feature, the nullable variant is used.
+pub(super) fn read_feature_collection(
+    geo_json: &str,
+    time_properties: &GeoJsonTimeProperties,
+    time_property: &str,
+) -> Result<GeoJsonFeatures> {
+    let geo_json: Value =
+        serde_json::from_str(geo_json).map_err(|error| error::Error::GeoJson {
+            details: error.to_string(),
+        })?;
+
+    ensure!(
+        geo_json["type"] == "FeatureCollection",
+        error::GeoJson {
+            details: "Input must be a GeoJSON FeatureCollection"
+        }
+    );
+
+    let features = geo_json["features"]
+        .as_array()
+        .ok_or_else(|| error::Error::GeoJson {
+            details: "FeatureCollection must contain a `features` array".to_string(),
+        })?;
+
+    let mut geometries = Vec::with_capacity(features.len());
+    let mut time_intervals = Vec::with_capacity(features.len());
+    let mut properties = Vec::with_capacity(features.len());
+
+    for feature in features {
+        let feature_properties = match &feature["properties"] {
+            Value::Object(feature_properties) => feature_properties.clone(),
+            Value::Null => Map::new(),
+            _ => {
+                return error::GeoJson {
+                    details: "Feature properties must be an object",
+                }
+                .fail()
+            }
+        };
+
+        geometries.push(feature["geometry"].clone());
+        time_intervals.push(time_properties.time_interval(&feature_properties, time_property)?);
+        properties.push(feature_properties);
+    }
+
+    let mut column_names: Vec<&String> = properties
+        .iter()
+        .flat_map(Map::keys)
+        .filter(|property| !time_properties.is_time_property(property, time_property))
+        .collect();
+    column_names.sort_unstable();
+    column_names.dedup();
+
+    let columns = column_names
+        .into_iter()
+        .map(|column| {
+            let data_type = infer_data_type(column, &properties)?;
+            Ok((column.clone(), data_type))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(GeoJsonFeatures {
+        geometries,
+        time_intervals,
+        columns,
+        properties,
+    })
+}
+
+fn infer_data_type(column: &str, properties: &[Map<String, Value>]) -> Result<FeatureDataType> {
+    let mut nullable = false;
+    let mut has_text = false;
+    let mut has_decimal = false;
+    let mut has_number = false;
+    let mut has_bool = false;
+
+    for feature_properties in properties {
+        match feature_properties.get(column) {
+            None | Some(Value::Null) => nullable = true,
+            Some(Value::String(_)) => has_text = true,
+            Some(Value::Number(number)) if number.is_i64() => has_decimal = true,
+            Some(Value::Number(_)) => has_number = true,
+            Some(Value::Bool(_)) => has_bool = true,
+            Some(value) => {
+                return error::GeoJson {
+                    details: format!("Property {} has unsupported value {}", column, value),
+                }
+                .fail()
+            }
+        }
+    }
+
+    ensure!(
+        !(has_text && (has_decimal || has_number)),
+        error::GeoJson {
+            details: format!("Property {} mixes texts and numbers", column),
+        }
+    );
+
+    ensure!(
+        !(has_bool && (has_text || has_decimal || has_number)),
+        error::GeoJson {
+            details: format!("Property {} mixes booleans with other values", column),
+        }
+    );
+
+    if has_bool {
+        return Ok(if nullable {
+            FeatureDataType::NullableBool
+        } else {
+            FeatureDataType::Bool
+        });
+    }
+
+    Ok(match (has_text, has_number, has_decimal, nullable) {
+        (true, _, _, false) => FeatureDataType::Text,
+        (false, true, _, false) => FeatureDataType::Number,
+        (false, false, true, false) => FeatureDataType::Decimal,
+        (false, true, _, true) => FeatureDataType::NullableNumber,
+        (false, false, true, true) => FeatureDataType::NullableDecimal,
+        // only nulls are treated as text
+        _ => FeatureDataType::NullableText,
+    })
+}
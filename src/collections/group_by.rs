@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+
+use snafu::ensure;
+
+use crate::collections::join::{self, KeyValue};
+use crate::collections::{helpers, FeatureCollection};
+use crate::error;
+use crate::primitives::{FeatureData, TimeInterval};
+use crate::util::Result;
+
+/// A function that aggregates the values of a numeric column per group
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AggregateFunction {
+    /// The number of non-null values as a decimal
+    Count,
+    /// The sum of the non-null values, which fails if decimals overflow
+    Sum,
+    /// The smallest non-null value
+    Min,
+    /// The largest non-null value
+    Max,
+    /// The arithmetic mean of the non-null values as a number
+    Mean,
+    /// The value of the first feature of the group
+    First,
+}
+
+/// An aggregation of the numeric column `column` into the output column `name`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Aggregate {
+    pub function: AggregateFunction,
+    pub column: String,
+    pub name: String,
+}
+
+impl Aggregate {
+    pub fn new(function: AggregateFunction, column: &str, name: &str) -> Self {
+        Self {
+            function,
+            column: column.to_string(),
+            name: name.to_string(),
+        }
+    }
+}
+
+/// The way the values of a column are reduced to a single value per group
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ColumnReduction {
+    /// Use the value of the first feature of the group
+    First,
+    /// Use the value that all features of the group share and fail if they differ
+    Unique,
+}
+
+/// The way the time intervals of a group are merged
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeMerge {
+    /// Use `TimeInterval::union` and fail if the intervals of a group leave a gap
+    Union,
+    /// Use the smallest interval that contains all intervals of a group
+    Hull,
+}
+
+/// Groups the features of `collection` by the values of the key columns `keys`.
+///
+/// Groups are ordered by their first feature and null values form groups of their own.
+pub(super) fn group_indices<C: FeatureCollection>(
+    collection: &C,
+    keys: &[&str],
+) -> Result<Vec<Vec<usize>>> {
+    let mut group_keys: Vec<Vec<Option<KeyValue>>> =
+        vec![Vec::with_capacity(keys.len()); collection.len()];
+
+    for &key in keys {
+        for (group_key, value) in group_keys
+            .iter_mut()
+            .zip(join::key_values(collection, key)?)
+        {
+            group_key.push(value);
+        }
+    }
+
+    let mut group_numbers: HashMap<Vec<Option<KeyValue>>, usize> = HashMap::new();
+    let mut groups: Vec<Vec<usize>> = Vec::new();
+
+    for (row, group_key) in group_keys.into_iter().enumerate() {
+        let group_number = *group_numbers.entry(group_key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group_number].push(row);
+    }
+
+    Ok(groups)
+}
+
+/// Computes `aggregate` for each of the (non-empty) `groups` of `collection`
+pub(super) fn aggregate<C: FeatureCollection>(
+    collection: &C,
+    groups: &[Vec<usize>],
+    aggregate: &Aggregate,
+) -> Result<FeatureData> {
+    ensure!(
+        collection.column_types().contains_key(&aggregate.column),
+        error::FeatureCollection {
+            details: format!("Column {} does not exist", aggregate.column),
+        }
+    );
+
+    let data =
+        helpers::feature_data_from_ref(&collection.data(&aggregate.column)?, collection.len())?;
+
+    Ok(match data {
+        FeatureData::Number(values) => {
+            aggregate_numbers(&all_some(values), groups, aggregate.function, false)
+        }
+        FeatureData::NullableNumber(values) => {
+            aggregate_numbers(&values, groups, aggregate.function, true)
+        }
+        FeatureData::Decimal(values) => {
+            aggregate_decimals(&all_some(values), groups, aggregate.function, false)?
+        }
+        FeatureData::NullableDecimal(values) => {
+            aggregate_decimals(&values, groups, aggregate.function, true)?
+        }
+        FeatureData::Text(_)
+        | FeatureData::NullableText(_)
+        | FeatureData::Categorical(_)
+        | FeatureData::NullableCategorical(_)
+        | FeatureData::Bool(_)
+        | FeatureData::NullableBool(_) => {
+            return error::FeatureCollection {
+                details: format!(
+                    "Cannot aggregate the non-numeric column {}",
+                    aggregate.column
+                ),
+            }
+            .fail()
+        }
+    })
+}
+
+/// Reduces the column `column` of `collection` to a value for each of the (non-empty) `groups`
+pub(super) fn reduce<C: FeatureCollection>(
+    collection: &C,
+    groups: &[Vec<usize>],
+    column: &str,
+    reduction: ColumnReduction,
+) -> Result<FeatureData> {
+    fn is_unique<T: PartialEq>(values: &[T], groups: &[Vec<usize>]) -> bool {
+        groups
+            .iter()
+            .all(|group| group.iter().all(|&row| values[row] == values[group[0]]))
+    }
+
+    let data = helpers::feature_data_from_ref(&collection.data(column)?, collection.len())?;
+
+    if reduction == ColumnReduction::Unique {
+        let unique = match &data {
+            FeatureData::Text(values) => is_unique(values, groups),
+            FeatureData::NullableText(values) => is_unique(values, groups),
+            FeatureData::Number(values) => is_unique(values, groups),
+            FeatureData::NullableNumber(values) => is_unique(values, groups),
+            FeatureData::Decimal(values) => is_unique(values, groups),
+            FeatureData::NullableDecimal(values) => is_unique(values, groups),
+            FeatureData::Categorical(values) => is_unique(values, groups),
+            FeatureData::NullableCategorical(values) => is_unique(values, groups),
+            FeatureData::Bool(values) => is_unique(values, groups),
+            FeatureData::NullableBool(values) => is_unique(values, groups),
+        };
+
+        ensure!(
+            unique,
+            error::FeatureCollection {
+                details: format!(
+                    "Column {} has different values within a group of features",
+                    column
+                ),
+            }
+        );
+    }
+
+    let first_rows: Vec<Option<usize>> = groups.iter().map(|group| Some(group[0])).collect();
+
+    Ok(join::gather_feature_data(data, &first_rows, false))
+}
+
+fn all_some<T>(values: Vec<T>) -> Vec<Option<T>> {
+    values.into_iter().map(Some).collect()
+}
+
+/// The non-null values of a group
+fn group_values<'v, T: Copy>(
+    values: &'v [Option<T>],
+    group: &'v [usize],
+) -> impl Iterator<Item = T> + 'v {
+    group.iter().filter_map(move |&row| values[row])
+}
+
+fn count<T: Copy>(values: &[Option<T>], groups: &[Vec<usize>]) -> FeatureData {
+    FeatureData::Decimal(
+        groups
+            .iter()
+            .map(|group| group_values(values, group).count() as i64)
+            .collect(),
+    )
+}
+
+fn mean(values: &[Option<f64>], groups: &[Vec<usize>], nullable: bool) -> FeatureData {
+    numbers(
+        groups
+            .iter()
+            .map(|group| {
+                let (sum, count) = group_values(values, group)
+                    .fold((0., 0), |(sum, count), value| (sum + value, count + 1));
+                if count == 0 {
+                    None
+                } else {
+                    Some(sum / f64::from(count))
+                }
+            })
+            .collect(),
+        nullable,
+    )
+}
+
+/// Creates a number column that is only nullable if `nullable` is set
+fn numbers(values: Vec<Option<f64>>, nullable: bool) -> FeatureData {
+    if nullable {
+        FeatureData::NullableNumber(values)
+    } else {
+        FeatureData::Number(
+            values
+                .into_iter()
+                .map(|value| value.expect("groups of non-nullable columns have values"))
+                .collect(),
+        )
+    }
+}
+
+/// Creates a decimal column that is only nullable if `nullable` is set
+fn decimals(values: Vec<Option<i64>>, nullable: bool) -> FeatureData {
+    if nullable {
+        FeatureData::NullableDecimal(values)
+    } else {
+        FeatureData::Decimal(
+            values
+                .into_iter()
+                .map(|value| value.expect("groups of non-nullable columns have values"))
+                .collect(),
+        )
+    }
+}
+
+fn aggregate_numbers(
+    values: &[Option<f64>],
+    groups: &[Vec<usize>],
+    function: AggregateFunction,
+    nullable: bool,
+) -> FeatureData {
+    let fold = |f: fn(f64, f64) -> f64| {
+        groups
+            .iter()
+            .map(|group| {
+                group_values(values, group).fold(None, |acc, value| match acc {
+                    Some(acc) => Some(f(acc, value)),
+                    None => Some(value),
+                })
+            })
+            .collect()
+    };
+
+    match function {
+        AggregateFunction::Count => count(values, groups),
+        AggregateFunction::Sum => numbers(fold(|sum, value| sum + value), nullable),
+        AggregateFunction::Min => numbers(fold(f64::min), nullable),
+        AggregateFunction::Max => numbers(fold(f64::max), nullable),
+        AggregateFunction::Mean => mean(values, groups, nullable),
+        AggregateFunction::First => numbers(
+            groups.iter().map(|group| values[group[0]]).collect(),
+            nullable,
+        ),
+    }
+}
+
+fn aggregate_decimals(
+    values: &[Option<i64>],
+    groups: &[Vec<usize>],
+    function: AggregateFunction,
+    nullable: bool,
+) -> Result<FeatureData> {
+    let fold = |f: fn(i64, i64) -> i64| {
+        groups
+            .iter()
+            .map(|group| {
+                group_values(values, group).fold(None, |acc, value| match acc {
+                    Some(acc) => Some(f(acc, value)),
+                    None => Some(value),
+                })
+            })
+            .collect()
+    };
+
+    Ok(match function {
+        AggregateFunction::Count => count(values, groups),
+        AggregateFunction::Sum => decimals(checked_sums(values, groups)?, nullable),
+        AggregateFunction::Min => decimals(fold(Ord::min), nullable),
+        AggregateFunction::Max => decimals(fold(Ord::max), nullable),
+        AggregateFunction::Mean => {
+            let values: Vec<Option<f64>> = values
+                .iter()
+                .map(|value| value.map(|value| value as f64))
+                .collect();
+            mean(&values, groups, nullable)
+        }
+        AggregateFunction::First => decimals(
+            groups.iter().map(|group| values[group[0]]).collect(),
+            nullable,
+        ),
+    })
+}
+
+/// The sums of the non-null values of each group, which are null for groups without values
+fn checked_sums(values: &[Option<i64>], groups: &[Vec<usize>]) -> Result<Vec<Option<i64>>> {
+    groups
+        .iter()
+        .map(|group| {
+            group_values(values, group).try_fold(None, |sum, value| match sum {
+                Some(sum) => i64::checked_add(sum, value).map(Some).ok_or_else(|| {
+                    error::Error::FeatureCollection {
+                        details: "The sum of a group of decimals overflows".to_string(),
+                    }
+                }),
+                None => Ok(Some(value)),
+            })
+        })
+        .collect()
+}
+
+/// Merges the time intervals of each of the (non-empty) `groups`
+pub(super) fn merge_time_intervals(
+    time_intervals: &[TimeInterval],
+    groups: &[Vec<usize>],
+    time_merge: TimeMerge,
+) -> Result<Vec<TimeInterval>> {
+    groups
+        .iter()
+        .map(|group| {
+            // unions must be built in temporal order to not fail on intervals that close a gap later on
+            let mut group_time_intervals: Vec<TimeInterval> =
+                group.iter().map(|&row| time_intervals[row]).collect();
+            group_time_intervals.sort_unstable_by_key(TimeInterval::start);
+
+            let first = group_time_intervals[0];
+            let mut others = group_time_intervals[1..].iter().copied();
+
+            match time_merge {
+                TimeMerge::Union => others.try_fold(first, |merged, other| merged.union(&other)),
+                TimeMerge::Hull => Ok(others.fold(first, |merged, other| {
+                    TimeInterval::new_unchecked(
+                        merged.start().min(other.start()),
+                        merged.end().max(other.end()),
+                    )
+                })),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,868 @@
+use std::collections::HashMap;
+use std::slice;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayBuilder, ArrayData, ArrayDataRef, ArrayRef, BooleanArray, BooleanBuilder,
+    Date64Array, Date64Builder, FixedSizeListArray, FixedSizeListBuilder, Float64Array,
+    Float64Builder, Int64Array, Int64Builder, ListArray, StringArray, StringBuilder, StructArray,
+    UInt32Array, UInt8Array, UInt8Builder,
+};
+use arrow::buffer::{Buffer, MutableBuffer};
+use arrow::compute::kernels::take::take;
+use arrow::datatypes::{DataType, DateUnit, Field, ToByteSlice};
+use arrow::util::bit_util;
+use snafu::ensure;
+
+use crate::collections::FeatureCollection;
+use crate::error;
+use crate::primitives::{
+    BoolDataRef, CategoricalDataRef, DecimalDataRef, FeatureData, FeatureDataRef, FeatureDataType,
+    FeatureDataValue, NullableBoolDataRef, NullableCategoricalDataRef, NullableDataRef,
+    NullableDecimalDataRef, NullableNumberDataRef, NullableTextDataRef, NumberDataRef, TextDataRef,
+    TimeInterval,
+};
+use crate::util::arrow::{downcast_array, downcast_mut_array};
+use crate::util::Result;
+
+/// Retrieve the composite arrow data type for time intervals
+pub(super) fn time_data_type() -> DataType {
+    DataType::FixedSizeList(DataType::Date64(DateUnit::Millisecond).into(), 2)
+}
+
+/// Create an arrow builder that contains the given time intervals
+pub(super) fn time_intervals_builder(
+    time_intervals: &[TimeInterval],
+) -> Result<FixedSizeListBuilder<Date64Builder>> {
+    let mut builder = FixedSizeListBuilder::new(Date64Builder::new(time_intervals.len()), 2);
+    for time_interval in time_intervals {
+        let date_builder = builder.values();
+        date_builder.append_value(time_interval.start())?;
+        date_builder.append_value(time_interval.end())?;
+        builder.append(true)?;
+    }
+    Ok(builder)
+}
+
+/// Retrieve the time intervals of the `time_column` of a collection
+pub(super) fn time_intervals_slice<'d>(
+    data: &'d StructArray,
+    time_column: &str,
+) -> &'d [TimeInterval] {
+    let features_ref = data
+        .column_by_name(time_column)
+        .expect("There must exist a time interval column");
+    let features: &FixedSizeListArray = downcast_array(features_ref);
+
+    let number_of_time_intervals = data.len();
+
+    let timestamps_ref = features.values();
+    let timestamps: &Date64Array = downcast_array(&timestamps_ref);
+
+    unsafe {
+        slice::from_raw_parts(
+            timestamps.raw_values() as *const TimeInterval,
+            number_of_time_intervals,
+        )
+    }
+}
+
+/// Retrieve a reference to the data of a (non-reserved) column
+pub(super) fn feature_data_ref<'d>(
+    data: &'d StructArray,
+    types: &HashMap<String, FeatureDataType>,
+    column_name: &str,
+) -> Result<FeatureDataRef<'d>> {
+    let column = data.column_by_name(column_name);
+
+    ensure!(
+        column.is_some(),
+        error::FeatureCollection {
+            details: format!(
+                "The column {} does not exist in the collection",
+                column_name
+            )
+        }
+    );
+
+    let column = column.unwrap(); // previously checked
+
+    Ok(match types.get(column_name).unwrap() {
+        // previously checked
+        FeatureDataType::Number => {
+            let array: &Float64Array = downcast_array(column);
+            NumberDataRef::new_with_offset(array.values(), array.offset(), array.len()).into()
+        }
+        FeatureDataType::NullableNumber => {
+            let array: &Float64Array = downcast_array(column);
+            NullableNumberDataRef::new_with_offset(
+                array.values(),
+                array.data_ref().null_bitmap(),
+                array.offset(),
+                array.len(),
+            )
+            .into()
+        }
+        FeatureDataType::Text => {
+            let array: &StringArray = downcast_array(column);
+            TextDataRef::new_with_offset(
+                array.value_data(),
+                array.value_offsets(),
+                array.offset(),
+                array.len(),
+            )
+            .into()
+        }
+        FeatureDataType::NullableText => {
+            let array: &StringArray = downcast_array(column);
+            NullableTextDataRef::new_with_offset(
+                array.value_data(),
+                array.value_offsets(),
+                array.offset(),
+                array.len(),
+            )
+            .into()
+        }
+        FeatureDataType::Decimal => {
+            let array: &Int64Array = downcast_array(column);
+            DecimalDataRef::new_with_offset(array.values(), array.offset(), array.len()).into()
+        }
+        FeatureDataType::NullableDecimal => {
+            let array: &Int64Array = downcast_array(column);
+            NullableDecimalDataRef::new_with_offset(
+                array.values(),
+                array.data_ref().null_bitmap(),
+                array.offset(),
+                array.len(),
+            )
+            .into()
+        }
+        FeatureDataType::Categorical => {
+            let array: &UInt8Array = downcast_array(column);
+            CategoricalDataRef::new_with_offset(array.values(), array.offset(), array.len()).into()
+        }
+        FeatureDataType::NullableCategorical => {
+            let array: &UInt8Array = downcast_array(column);
+            NullableCategoricalDataRef::new_with_offset(
+                array.values(),
+                array.data_ref().null_bitmap(),
+                array.offset(),
+                array.len(),
+            )
+            .into()
+        }
+        FeatureDataType::Bool => {
+            let array: &BooleanArray = downcast_array(column);
+            BoolDataRef::new(array.values(), array.offset(), array.len()).into()
+        }
+        FeatureDataType::NullableBool => {
+            let array: &BooleanArray = downcast_array(column);
+            NullableBoolDataRef::new(
+                array.values(),
+                array.data_ref().null_bitmap(),
+                array.offset(),
+                array.len(),
+            )
+            .into()
+        }
+    })
+}
+
+/// Copy the first `len` values of a data column into owned feature data
+pub(super) fn feature_data_from_ref(data: &FeatureDataRef, len: usize) -> Result<FeatureData> {
+    fn with_nulls<T: Copy>(values: &[T], nulls: Vec<bool>) -> Vec<Option<T>> {
+        values
+            .iter()
+            .zip(nulls)
+            .map(|(&value, is_null)| if is_null { None } else { Some(value) })
+            .collect()
+    }
+
+    Ok(match data {
+        FeatureDataRef::Number(numbers) => FeatureData::Number(numbers.as_ref()[..len].to_vec()),
+        FeatureDataRef::NullableNumber(numbers) => {
+            FeatureData::NullableNumber(with_nulls(&numbers.as_ref()[..len], numbers.nulls()))
+        }
+        FeatureDataRef::Decimal(decimals) => {
+            FeatureData::Decimal(decimals.as_ref()[..len].to_vec())
+        }
+        FeatureDataRef::NullableDecimal(decimals) => {
+            FeatureData::NullableDecimal(with_nulls(&decimals.as_ref()[..len], decimals.nulls()))
+        }
+        FeatureDataRef::Categorical(categories) => {
+            FeatureData::Categorical(categories.as_ref()[..len].to_vec())
+        }
+        FeatureDataRef::NullableCategorical(categories) => FeatureData::NullableCategorical(
+            with_nulls(&categories.as_ref()[..len], categories.nulls()),
+        ),
+        FeatureDataRef::Text(texts) => FeatureData::Text(
+            (0..len)
+                .map(|i| texts.text_at(i).map(str::to_string))
+                .collect::<Result<_>>()?,
+        ),
+        FeatureDataRef::NullableText(texts) => FeatureData::NullableText(
+            (0..len)
+                .map(|i| texts.text_at(i).map(|text| text.map(str::to_string)))
+                .collect::<Result<_>>()?,
+        ),
+        FeatureDataRef::Bool(bools) => {
+            FeatureData::Bool((0..len).map(|i| bools.value_at(i)).collect::<Result<_>>()?)
+        }
+        FeatureDataRef::NullableBool(bools) => {
+            FeatureData::NullableBool((0..len).map(|i| bools.value_at(i)).collect::<Result<_>>()?)
+        }
+    })
+}
+
+/// List the columns whose data types differ between two collections, sorted by name
+pub(super) fn schema_differences(
+    types: &HashMap<String, FeatureDataType>,
+    other_types: &HashMap<String, FeatureDataType>,
+) -> Vec<String> {
+    let mut columns: Vec<&String> = types.keys().chain(other_types.keys()).collect();
+    columns.sort_unstable();
+    columns.dedup();
+
+    columns
+        .into_iter()
+        .filter_map(
+            |column| match (types.get(column), other_types.get(column)) {
+                (Some(data_type), Some(other_data_type)) if data_type == other_data_type => None,
+                (data_type, other_data_type) => Some(format!(
+                    "{} ({} vs. {})",
+                    column,
+                    data_type.map_or_else(|| "missing".to_string(), |t| format!("{:?}", t)),
+                    other_data_type.map_or_else(|| "missing".to_string(), |t| format!("{:?}", t)),
+                )),
+            },
+        )
+        .collect()
+}
+
+/// Retrieve the fields and the arrays of all columns of a collection
+pub(super) fn fields_and_columns(data: &StructArray) -> Vec<(Field, ArrayRef)> {
+    if let DataType::Struct(fields) = data.data().data_type() {
+        fields.iter().cloned().zip(data.columns_ref()).collect()
+    } else {
+        unreachable!("data column must be a struct")
+    }
+}
+
+/// Create a new struct array that contains all columns of `data` and an additional column
+pub(super) fn struct_array_with_column(
+    data: &StructArray,
+    new_column: &str,
+    feature_data: &FeatureData,
+) -> Result<StructArray> {
+    let (mut columns, mut column_values): (Vec<Field>, Vec<ArrayRef>) =
+        fields_and_columns(data).into_iter().unzip();
+
+    columns.push(Field::new(
+        new_column,
+        feature_data.arrow_data_type(),
+        feature_data.nullable(),
+    ));
+    column_values.push(
+        feature_data
+            .arrow_builder()
+            .map(|mut builder| builder.finish())?,
+    );
+
+    Ok(struct_array_from_data(columns, column_values, data.len()))
+}
+
+/// Create a new struct array that contains all columns of `data` except `column`
+pub(super) fn struct_array_without_column(data: &StructArray, column: &str) -> StructArray {
+    let (columns, column_values): (Vec<Field>, Vec<ArrayRef>) = fields_and_columns(data)
+        .into_iter()
+        .filter(|(field, _)| field.name() != column)
+        .unzip();
+
+    struct_array_from_data(columns, column_values, data.len())
+}
+
+/// Create a new struct array where the column `old_name` is called `new_name`, sharing all arrays
+pub(super) fn struct_array_with_renamed_column(
+    data: &StructArray,
+    old_name: &str,
+    new_name: &str,
+) -> StructArray {
+    let (columns, column_values): (Vec<Field>, Vec<ArrayRef>) = fields_and_columns(data)
+        .into_iter()
+        .map(|(field, column)| {
+            if field.name() == old_name {
+                let renamed = Field::new(new_name, field.data_type().clone(), field.is_nullable());
+                (renamed, column)
+            } else {
+                (field, column)
+            }
+        })
+        .unzip();
+
+    struct_array_from_data(columns, column_values, data.len())
+}
+
+/// Create a new struct array that contains only the columns of `data` for which `keep` holds, sharing their arrays
+pub(super) fn struct_array_with_columns<F>(data: &StructArray, keep: F) -> StructArray
+where
+    F: Fn(&str) -> bool,
+{
+    let (columns, column_values): (Vec<Field>, Vec<ArrayRef>) = fields_and_columns(data)
+        .into_iter()
+        .filter(|(field, _)| keep(field.name()))
+        .unzip();
+
+    struct_array_from_data(columns, column_values, data.len())
+}
+
+/// Filter all columns of `data` by a mask.
+///
+/// The `feature_filter` is applied to the feature column and `time_interval_filter` to the time column.
+/// All other columns are taken at the indices of the mask since the arrow filter kernel drops the nulls of strings.
+pub(super) fn filter_struct_array<F>(
+    data: &StructArray,
+    filter_array: &BooleanArray,
+    feature_column: &str,
+    feature_filter: F,
+    time_column: &str,
+) -> Result<StructArray>
+where
+    F: Fn(&ArrayRef, &BooleanArray) -> Result<ArrayRef>,
+{
+    // TODO: use filter directly on struct array when it is implemented
+
+    if !(0..filter_array.len()).any(|i| filter_array.value(i)) {
+        // arrow cannot build fixed size lists without any rows
+        let columns = fields_and_columns(data)
+            .into_iter()
+            .map(|(field, _)| field)
+            .collect();
+        return Ok(empty_struct_array(columns));
+    }
+
+    let indices: Vec<usize> = (0..filter_array.len())
+        .filter(|&i| filter_array.value(i))
+        .collect();
+
+    let mut filtered_data: Vec<(Field, ArrayRef)> = Vec::with_capacity(data.num_columns());
+    for (column, array) in fields_and_columns(data) {
+        let filtered_array = if column.name() == feature_column {
+            feature_filter(&array, filter_array)?
+        } else if column.name() == time_column {
+            Arc::new(time_interval_filter(downcast_array(&array), filter_array)?)
+        } else {
+            array_from_data(take_array_data(array.data_ref(), &indices)?)
+        };
+        filtered_data.push((column, filtered_array));
+    }
+
+    Ok(filtered_data.into())
+}
+
+/// Select the rows at `indices` of all columns of `data`, including nested geometry columns
+pub(super) fn take_struct_array(data: &StructArray, indices: &[usize]) -> Result<StructArray> {
+    if let Some(&index) = indices.iter().find(|&&index| index >= data.len()) {
+        return error::FeatureCollection {
+            details: format!(
+                "Index {} is out of bounds for a collection of length {}",
+                index,
+                data.len()
+            ),
+        }
+        .fail();
+    }
+
+    let (columns, column_values): (Vec<Field>, Vec<ArrayRef>) = fields_and_columns(data)
+        .into_iter()
+        .map(|(field, column)| {
+            Ok((
+                field,
+                array_from_data(take_array_data(column.data_ref(), indices)?),
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .unzip();
+
+    Ok(struct_array_from_data(
+        columns,
+        column_values,
+        indices.len(),
+    ))
+}
+
+fn take_array_data(data: &ArrayDataRef, indices: &[usize]) -> Result<ArrayDataRef> {
+    let offset = data.offset();
+
+    let mut builder = ArrayData::builder(data.data_type().clone()).len(indices.len());
+    if let Some(null_bitmap) = data.null_bitmap() {
+        builder = builder.null_bit_buffer(bitmap_buffer(indices.len(), |i| {
+            null_bitmap.is_set(offset + indices[i])
+        }));
+    }
+
+    Ok(match data.data_type() {
+        DataType::List(_) => {
+            let list = ListArray::from(data.clone());
+
+            let mut offsets: Vec<i32> = Vec::with_capacity(indices.len() + 1);
+            let mut child_indices = Vec::new();
+            offsets.push(0);
+            for &index in indices {
+                let start = list.value_offset(index) as usize;
+                let end = start + list.value_length(index) as usize;
+                child_indices.extend(start..end);
+                offsets.push(child_indices.len() as i32);
+            }
+
+            builder
+                .add_buffer(Buffer::from(offsets.to_byte_slice()))
+                .add_child_data(take_array_data(&data.child_data()[0], &child_indices)?)
+                .build()
+        }
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let child_indices: Vec<usize> = indices
+                .iter()
+                .flat_map(|&index| (offset + index) * size..(offset + index + 1) * size)
+                .collect();
+
+            builder
+                .add_child_data(take_array_data(&data.child_data()[0], &child_indices)?)
+                .build()
+        }
+        _ => {
+            let indices: UInt32Array = indices
+                .iter()
+                .map(|&index| index as u32)
+                .collect::<Vec<_>>()
+                .into();
+            take(&array_from_data(data.clone()), &indices, None)?.data()
+        }
+    })
+}
+
+/// Select `len` rows starting at `offset` of all columns of `data`.
+///
+/// All value buffers and validity bitmaps are shared with `data` and addressed by the offset of
+/// the sliced array data, since arrow cannot reliably slice buffers that are already sliced.
+/// Only the offsets of list columns are rebased and their validity is dropped unless they have nulls.
+pub(super) fn slice_struct_array(
+    data: &StructArray,
+    offset: usize,
+    len: usize,
+) -> Result<StructArray> {
+    ensure!(
+        offset + len <= data.len(),
+        error::FeatureCollection {
+            details: format!(
+                "Slice of length {} at offset {} is out of bounds for a collection of length {}",
+                len,
+                offset,
+                data.len()
+            ),
+        }
+    );
+
+    let (columns, column_values): (Vec<Field>, Vec<ArrayRef>) = fields_and_columns(data)
+        .into_iter()
+        .map(|(field, column)| {
+            (
+                field,
+                array_from_data(slice_array_data(column.data_ref(), offset, len)),
+            )
+        })
+        .unzip();
+
+    Ok(struct_array_from_data(columns, column_values, len))
+}
+
+/// Slice the array `data`, where `nested` primitive arrays keep their buffers and are sliced by
+/// their offset since their parents bound their length
+fn slice_array_data(data: &ArrayDataRef, offset: usize, len: usize) -> ArrayDataRef {
+    let offset = data.offset() + offset;
+
+    let mut builder = ArrayData::builder(data.data_type().clone()).len(len);
+
+    match data.data_type() {
+        DataType::List(_) | DataType::FixedSizeList(_, _) => {}
+        _ => {
+            // values, text offsets and validity are addressed through the offset
+            if let Some(null_buffer) = data.null_buffer() {
+                builder = builder.null_bit_buffer(null_buffer.clone());
+            }
+            for buffer in data.buffers() {
+                builder = builder.add_buffer(buffer.clone());
+            }
+            return builder.offset(offset).build();
+        }
+    }
+
+    // lists are rebased since not every reader honors their offset
+    // and geometries and time intervals are never null
+    if data.null_count() > 0 {
+        if let Some(null_bitmap) = data.null_bitmap() {
+            builder =
+                builder.null_bit_buffer(bitmap_buffer(len, |i| null_bitmap.is_set(offset + i)));
+        }
+    }
+
+    match data.data_type() {
+        DataType::List(_) => {
+            let offsets = &data.buffers()[0].typed_data::<i32>()[offset..=offset + len];
+            let start = offsets[0];
+            let end = offsets[len];
+            let rebased_offsets: Vec<i32> = offsets.iter().map(|&o| o - start).collect();
+
+            builder
+                .add_buffer(Buffer::from(rebased_offsets.to_byte_slice()))
+                .add_child_data(slice_array_data(
+                    &data.child_data()[0],
+                    start as usize,
+                    (end - start) as usize,
+                ))
+                .build()
+        }
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            builder
+                .add_child_data(slice_array_data(
+                    &data.child_data()[0],
+                    offset * size,
+                    len * size,
+                ))
+                .build()
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Concatenate the rows of all `arrays`, which must have the columns of the first one.
+///
+/// Columns are matched by name since their order depends on how a collection was built.
+/// Values are copied once into new buffers and list offsets are shifted to the concatenated values.
+pub(super) fn concat_struct_arrays(arrays: &[&StructArray]) -> StructArray {
+    let len = arrays.iter().map(|array| array.len()).sum();
+
+    let (columns, column_values): (Vec<Field>, Vec<ArrayRef>) = fields_and_columns(arrays[0])
+        .into_iter()
+        .map(|(field, _)| {
+            let column_data: Vec<ArrayDataRef> = arrays
+                .iter()
+                .map(|array| {
+                    array
+                        .column_by_name(field.name())
+                        .expect("all arrays have the columns of the first one")
+                        .data()
+                })
+                .collect();
+            let ranges: Vec<(&ArrayDataRef, usize, usize)> = column_data
+                .iter()
+                .map(|data| (data, 0, data.len()))
+                .collect();
+
+            (field, array_from_data(concat_array_data(&ranges)))
+        })
+        .unzip();
+
+    struct_array_from_data(columns, column_values, len)
+}
+
+/// Concatenate the `len` values starting at `offset` of every array data of `ranges`
+fn concat_array_data(ranges: &[(&ArrayDataRef, usize, usize)]) -> ArrayDataRef {
+    // the offsets of the array data are applied, so that ranges address their buffers directly
+    let ranges: Vec<(&ArrayDataRef, usize, usize)> = ranges
+        .iter()
+        .map(|&(data, offset, len)| (data, data.offset() + offset, len))
+        .collect();
+    let positions = || {
+        ranges
+            .iter()
+            .flat_map(|&(data, offset, len)| (offset..offset + len).map(move |i| (data, i)))
+    };
+
+    let data_type = ranges[0].0.data_type();
+    let len = ranges.iter().map(|&(_, _, len)| len).sum();
+
+    let mut builder = ArrayData::builder(data_type.clone()).len(len);
+    if ranges.iter().any(|(data, _, _)| data.null_count() > 0) {
+        let validity: Vec<bool> = positions().map(|(data, i)| data.is_valid(i)).collect();
+        builder = builder.null_bit_buffer(bitmap_buffer(len, |i| validity[i]));
+    }
+
+    match data_type {
+        DataType::List(_) => {
+            let (offsets, value_ranges) = concat_offsets(&ranges);
+            let child_ranges: Vec<(&ArrayDataRef, usize, usize)> = ranges
+                .iter()
+                .zip(value_ranges)
+                .map(|(&(data, _, _), (start, len))| (&data.child_data()[0], start, len))
+                .collect();
+
+            builder
+                .add_buffer(Buffer::from(offsets.to_byte_slice()))
+                .add_child_data(concat_array_data(&child_ranges))
+                .build()
+        }
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let child_ranges: Vec<(&ArrayDataRef, usize, usize)> = ranges
+                .iter()
+                .map(|&(data, offset, len)| (&data.child_data()[0], offset * size, len * size))
+                .collect();
+
+            builder
+                .add_child_data(concat_array_data(&child_ranges))
+                .build()
+        }
+        DataType::Utf8 => {
+            let (offsets, value_ranges) = concat_offsets(&ranges);
+            let mut values: Vec<u8> = Vec::with_capacity(offsets[len] as usize);
+            for (&(data, _, _), (start, len)) in ranges.iter().zip(value_ranges) {
+                values.extend_from_slice(&data.buffers()[1].data()[start..start + len]);
+            }
+
+            builder
+                .add_buffer(Buffer::from(offsets.to_byte_slice()))
+                .add_buffer(Buffer::from(values))
+                .build()
+        }
+        DataType::Boolean => {
+            let values: Vec<bool> = positions()
+                .map(|(data, i)| bit_util::get_bit(data.buffers()[0].data(), i))
+                .collect();
+
+            builder
+                .add_buffer(bitmap_buffer(len, |i| values[i]))
+                .build()
+        }
+        data_type => {
+            let byte_width = match data_type {
+                DataType::Float64 | DataType::Int64 | DataType::Date64(_) => 8,
+                DataType::UInt8 => 1,
+                _ => unreachable!("collections do not contain columns of type {:?}", data_type),
+            };
+            let mut values: Vec<u8> = Vec::with_capacity(len * byte_width);
+            for &(data, offset, len) in &ranges {
+                values.extend_from_slice(
+                    &data.buffers()[0].data()[offset * byte_width..(offset + len) * byte_width],
+                );
+            }
+
+            builder.add_buffer(Buffer::from(values)).build()
+        }
+    }
+}
+
+/// Concatenate the offsets of the list or string `ranges`, shifted to follow each other.
+///
+/// Returns the concatenated offsets and the start and length of the values of each range.
+fn concat_offsets(ranges: &[(&ArrayDataRef, usize, usize)]) -> (Vec<i32>, Vec<(usize, usize)>) {
+    let mut offsets: Vec<i32> = vec![0];
+    let mut value_ranges = Vec::with_capacity(ranges.len());
+
+    for &(data, offset, len) in ranges {
+        let range_offsets = &data.buffers()[0].typed_data::<i32>()[offset..=offset + len];
+        let (start, end) = (range_offsets[0], range_offsets[len]);
+        let shift = offsets[offsets.len() - 1] - start;
+
+        offsets.extend(range_offsets[1..].iter().map(|&offset| offset + shift));
+        value_ranges.push((start as usize, (end - start) as usize));
+    }
+
+    (offsets, value_ranges)
+}
+
+/// Checks whether `data` or any of its children is addressed by an offset
+pub(super) fn has_array_offsets(data: &ArrayDataRef) -> bool {
+    data.offset() != 0 || data.child_data().iter().any(has_array_offsets)
+}
+
+/// Create an array of the concrete type of a collection column from its data
+fn array_from_data(data: ArrayDataRef) -> ArrayRef {
+    match data.data_type() {
+        DataType::List(_) => Arc::new(ListArray::from(data)),
+        DataType::FixedSizeList(_, _) => Arc::new(FixedSizeListArray::from(data)),
+        DataType::Utf8 => Arc::new(StringArray::from(data)),
+        DataType::Float64 => Arc::new(Float64Array::from(data)),
+        DataType::Int64 => Arc::new(Int64Array::from(data)),
+        DataType::UInt8 => Arc::new(UInt8Array::from(data)),
+        DataType::Date64(_) => Arc::new(Date64Array::from(data)),
+        DataType::Boolean => Arc::new(BooleanArray::from(data)),
+        data_type => unreachable!("collections do not contain columns of type {:?}", data_type),
+    }
+}
+
+/// Create a bitmap buffer of `len` bits where bit `i` is set if `is_set(i)` holds
+fn bitmap_buffer(len: usize, is_set: impl Fn(usize) -> bool) -> Buffer {
+    let number_of_bytes = bit_util::ceil(len, 8);
+    let mut buffer = MutableBuffer::new(number_of_bytes).with_bitset(number_of_bytes, false);
+    for i in (0..len).filter(|&i| is_set(i)) {
+        bit_util::set_bit(buffer.data_mut(), i);
+    }
+    buffer.freeze()
+}
+
+pub(super) fn time_interval_filter(
+    time_intervals: &FixedSizeListArray,
+    filter_array: &BooleanArray,
+) -> Result<FixedSizeListArray> {
+    let mut new_time_intervals = FixedSizeListBuilder::new(Date64Builder::new(2), 2);
+
+    for feature_index in 0..time_intervals.len() {
+        if !filter_array.value(feature_index) {
+            continue;
+        }
+
+        let old_timestamps_ref = time_intervals.value(feature_index);
+        let old_timestamps: &Date64Array = downcast_array(&old_timestamps_ref);
+
+        let date_builder = new_time_intervals.values();
+        date_builder.append_slice(old_timestamps.value_slice(0, 2))?;
+
+        new_time_intervals.append(true)?;
+    }
+
+    Ok(new_time_intervals.finish())
+}
+
+pub(super) fn struct_array_from_data(
+    columns: Vec<Field>,
+    column_values: Vec<ArrayRef>,
+    number_of_features: usize,
+) -> StructArray {
+    StructArray::from(
+        ArrayData::builder(DataType::Struct(columns))
+            .child_data(column_values.into_iter().map(|a| a.data()).collect())
+            .len(number_of_features)
+            .build(),
+    )
+}
+
+/// Create a struct array of `columns` without any features
+///
+/// This is needed since arrow cannot build fixed size lists without any rows
+pub(super) fn empty_struct_array(columns: Vec<Field>) -> StructArray {
+    let child_data = columns
+        .iter()
+        .map(|field| empty_array_data(field.data_type()))
+        .collect();
+
+    StructArray::from(
+        ArrayData::builder(DataType::Struct(columns))
+            .child_data(child_data)
+            .len(0)
+            .build(),
+    )
+}
+
+/// Create the data of an array of `data_type` without any values
+fn empty_array_data(data_type: &DataType) -> ArrayDataRef {
+    let builder = ArrayData::builder(data_type.clone()).len(0);
+
+    match data_type {
+        DataType::List(child_type) => builder
+            .add_buffer(Buffer::from([0_i32].to_byte_slice()))
+            .add_child_data(empty_array_data(child_type)),
+        DataType::FixedSizeList(child_type, _) => {
+            builder.add_child_data(empty_array_data(child_type))
+        }
+        DataType::Utf8 => builder
+            .add_buffer(Buffer::from([0_i32].to_byte_slice()))
+            .add_buffer(Buffer::from(&[][..])),
+        _ => builder.add_buffer(Buffer::from(&[][..])),
+    }
+    .build()
+}
+
+/// Append a single value to the arrow builder of a data column
+///
+/// The caller must be sure that the builder matches the value type, otherwise it panics
+pub(super) fn append_feature_data_value(
+    data_builder: &mut dyn ArrayBuilder,
+    data: FeatureDataValue,
+) -> Result<()> {
+    match data {
+        FeatureDataValue::Number(value) => {
+            let number_builder: &mut Float64Builder = downcast_mut_array(data_builder);
+            number_builder.append_value(value)?;
+        }
+        FeatureDataValue::NullableNumber(value) => {
+            let number_builder: &mut Float64Builder = downcast_mut_array(data_builder);
+            number_builder.append_option(value)?;
+        }
+        FeatureDataValue::Text(value) => {
+            let string_builder: &mut StringBuilder = downcast_mut_array(data_builder);
+            string_builder.append_value(&value)?;
+        }
+        FeatureDataValue::NullableText(value) => {
+            let string_builder: &mut StringBuilder = downcast_mut_array(data_builder);
+            if let Some(v) = &value {
+                string_builder.append_value(&v)?;
+            } else {
+                string_builder.append_null()?;
+            }
+        }
+        FeatureDataValue::Decimal(value) => {
+            let decimal_builder: &mut Int64Builder = downcast_mut_array(data_builder);
+            decimal_builder.append_value(value)?;
+        }
+        FeatureDataValue::NullableDecimal(value) => {
+            let decimal_builder: &mut Int64Builder = downcast_mut_array(data_builder);
+            decimal_builder.append_option(value)?;
+        }
+        FeatureDataValue::Categorical(value) => {
+            let categorical_builder: &mut UInt8Builder = downcast_mut_array(data_builder);
+            categorical_builder.append_value(value)?;
+        }
+        FeatureDataValue::NullableCategorical(value) => {
+            let categorical_builder: &mut UInt8Builder = downcast_mut_array(data_builder);
+            categorical_builder.append_option(value)?;
+        }
+        FeatureDataValue::Bool(value) => {
+            let bool_builder: &mut BooleanBuilder = downcast_mut_array(data_builder);
+            bool_builder.append_value(value)?;
+        }
+        FeatureDataValue::NullableBool(value) => {
+            let bool_builder: &mut BooleanBuilder = downcast_mut_array(data_builder);
+            bool_builder.append_option(value)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares the time intervals, column types and column values of two collections,
+/// where values of number columns are compared with `number_eq`
+pub(super) fn feature_data_eq<C, F>(a: &C, b: &C, number_eq: F) -> bool
+where
+    C: FeatureCollection,
+    F: Fn(f64, f64) -> bool,
+{
+    fn column(collection: &impl FeatureCollection, name: &str) -> Option<FeatureData> {
+        feature_data_from_ref(&collection.data(name).ok()?, collection.len()).ok()
+    }
+
+    if a.len() != b.len()
+        || a.time_intervals() != b.time_intervals()
+        || a.column_types() != b.column_types()
+    {
+        return false;
+    }
+
+    a.column_types()
+        .keys()
+        .all(|name| match (column(a, name), column(b, name)) {
+            (Some(FeatureData::Number(a)), Some(FeatureData::Number(b))) => {
+                a.iter().zip(&b).all(|(&a, &b)| number_eq(a, b))
+            }
+            (Some(FeatureData::NullableNumber(a)), Some(FeatureData::NullableNumber(b))) => {
+                a.iter().zip(&b).all(|(a, b)| match (a, b) {
+                    (Some(a), Some(b)) => number_eq(*a, *b),
+                    (a, b) => a == b,
+                })
+            }
+            (Some(a), Some(b)) => a == b,
+            _ => false,
+        })
+}
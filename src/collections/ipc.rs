@@ -0,0 +1,97 @@
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::array::{Array, StructArray};
+use arrow::datatypes::Schema;
+use arrow::ipc::reader::{FileReader, StreamReader};
+use arrow::ipc::writer::{FileWriter, StreamWriter};
+use arrow::record_batch::RecordBatch;
+use snafu::ensure;
+
+use crate::collections::helpers;
+use crate::error;
+use crate::util::Result;
+
+/// The Arrow IPC format to use for (de)serializing collections
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ArrowIpcFormat {
+    /// The streaming format, i.e., a schema followed by record batches
+    Stream,
+    /// The random access file format, i.e., the streaming format framed by a magic number and a footer
+    File,
+}
+
+/// Serialize the columns of a collection as a single record batch
+pub(super) fn struct_array_to_ipc(data: &StructArray, format: ArrowIpcFormat) -> Result<Vec<u8>> {
+    // the writer ignores the offsets of arrays, so slices are copied beforehand
+    let compacted;
+    let data = if helpers::has_array_offsets(data.data_ref()) {
+        compacted = helpers::take_struct_array(data, &(0..data.len()).collect::<Vec<_>>())?;
+        &compacted
+    } else {
+        data
+    };
+
+    let (fields, columns): (Vec<_>, Vec<_>) = helpers::fields_and_columns(data).into_iter().unzip();
+
+    let schema = Schema::new(fields);
+    let batch = RecordBatch::try_new(Arc::new(schema.clone()), columns)?;
+
+    let mut bytes = Vec::new();
+
+    match format {
+        ArrowIpcFormat::Stream => {
+            let mut writer = StreamWriter::try_new(&mut bytes, &schema)?;
+            writer.write(&batch)?;
+            writer.finish()?;
+        }
+        ArrowIpcFormat::File => {
+            let mut writer = FileWriter::try_new(&mut bytes, &schema)?;
+            writer.write(&batch)?;
+            writer.finish()?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Deserialize the columns of a collection that was written as a single record batch
+pub(super) fn struct_array_from_ipc(bytes: &[u8], format: ArrowIpcFormat) -> Result<StructArray> {
+    let mut batches = Vec::with_capacity(1);
+
+    match format {
+        ArrowIpcFormat::Stream => {
+            let mut reader = StreamReader::try_new(Cursor::new(bytes))?;
+            while let Some(batch) = reader.next()? {
+                batches.push(batch);
+            }
+        }
+        ArrowIpcFormat::File => {
+            let mut reader = FileReader::try_new(Cursor::new(bytes))?;
+            while let Some(batch) = reader.next()? {
+                batches.push(batch);
+            }
+        }
+    }
+
+    ensure!(
+        batches.len() == 1,
+        error::FeatureCollection {
+            details: format!(
+                "Expected exactly one record batch, but found {}",
+                batches.len()
+            )
+        }
+    );
+
+    let batch = batches.remove(0);
+
+    Ok(batch
+        .schema()
+        .fields()
+        .iter()
+        .cloned()
+        .zip((0..batch.num_columns()).map(|i| batch.column(i).clone()))
+        .collect::<Vec<_>>()
+        .into())
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use snafu::ensure;
+
+use crate::collections::{helpers, FeatureCollection};
+use crate::error;
+use crate::primitives::{FeatureData, FeatureDataType};
+use crate::util::Result;
+
+/// The kind of an attribute join
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JoinType {
+    /// Keep only features with a matching row in the other collection
+    Inner,
+    /// Keep all features and fill the columns of the other collection with nulls if there is no match
+    Left,
+}
+
+/// A single value of a key column
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(super) enum KeyValue {
+    Text(String),
+    Decimal(i64),
+    Categorical(u8),
+    Bool(bool),
+}
+
+/// Retrieve the values of the key column `column`, where nulls are `None`
+pub(super) fn key_values<C: FeatureCollection>(
+    collection: &C,
+    column: &str,
+) -> Result<Vec<Option<KeyValue>>> {
+    fn all_some<T>(values: Vec<T>, key_value: impl Fn(T) -> KeyValue) -> Vec<Option<KeyValue>> {
+        values.into_iter().map(|v| Some(key_value(v))).collect()
+    }
+
+    fn nullable<T>(
+        values: Vec<Option<T>>,
+        key_value: impl Fn(T) -> KeyValue,
+    ) -> Vec<Option<KeyValue>> {
+        values.into_iter().map(|v| v.map(&key_value)).collect()
+    }
+
+    ensure!(
+        collection.column_types().contains_key(column),
+        error::FeatureCollection {
+            details: format!("Key column {} does not exist", column),
+        }
+    );
+
+    Ok(
+        match helpers::feature_data_from_ref(&collection.data(column)?, collection.len())? {
+            FeatureData::Text(values) => all_some(values, KeyValue::Text),
+            FeatureData::NullableText(values) => nullable(values, KeyValue::Text),
+            FeatureData::Decimal(values) => all_some(values, KeyValue::Decimal),
+            FeatureData::NullableDecimal(values) => nullable(values, KeyValue::Decimal),
+            FeatureData::Categorical(values) => all_some(values, KeyValue::Categorical),
+            FeatureData::NullableCategorical(values) => nullable(values, KeyValue::Categorical),
+            FeatureData::Bool(values) => all_some(values, KeyValue::Bool),
+            FeatureData::NullableBool(values) => nullable(values, KeyValue::Bool),
+            FeatureData::Number(_) | FeatureData::NullableNumber(_) => {
+                return error::FeatureCollection {
+                    details: format!("Cannot use the number column {} as a key", column),
+                }
+                .fail()
+            }
+        },
+    )
+}
+
+/// Retrieve the key of each feature, where keys with null values are `None` since they never match
+fn join_keys<C: FeatureCollection>(
+    collection: &C,
+    on: &[&str],
+) -> Result<Vec<Option<Vec<KeyValue>>>> {
+    let mut keys: Vec<Option<Vec<KeyValue>>> =
+        vec![Some(Vec::with_capacity(on.len())); collection.len()];
+
+    for &column in on {
+        for (key, value) in keys.iter_mut().zip(key_values(collection, column)?) {
+            *key = match (key.take(), value) {
+                (Some(mut key), Some(value)) => {
+                    key.push(value);
+                    Some(key)
+                }
+                _ => None,
+            };
+        }
+    }
+
+    Ok(keys)
+}
+
+/// Pairs the features of `left` with the matching features of `right` by the key columns `on`.
+///
+/// A feature of `left` occurs once for each match in `right` and, for left joins, once with `None` if there is no match.
+pub(super) fn join_indices<L, R>(
+    left: &L,
+    right: &R,
+    on: &[&str],
+    join_type: JoinType,
+) -> Result<Vec<(usize, Option<usize>)>>
+where
+    L: FeatureCollection,
+    R: FeatureCollection,
+{
+    ensure!(
+        !on.is_empty(),
+        error::FeatureCollection {
+            details: "A join needs at least one key column",
+        }
+    );
+
+    for &column in on {
+        let left_type = left.column_types().get(column).copied().map(non_nullable);
+        let right_type = right.column_types().get(column).copied().map(non_nullable);
+        ensure!(
+            left_type.is_some() && left_type == right_type,
+            error::FeatureCollection {
+                details: format!(
+                    "Key column {} must exist in both collections with the same type",
+                    column
+                ),
+            }
+        );
+    }
+
+    let mut right_rows: HashMap<Vec<KeyValue>, Vec<usize>> = HashMap::new();
+    for (row, key) in join_keys(right, on)?.into_iter().enumerate() {
+        if let Some(key) = key {
+            right_rows.entry(key).or_default().push(row);
+        }
+    }
+
+    let mut indices = Vec::with_capacity(left.len());
+    for (row, key) in join_keys(left, on)?.into_iter().enumerate() {
+        match key.and_then(|key| right_rows.get(&key)) {
+            Some(right_rows) => {
+                indices.extend(right_rows.iter().map(|&right_row| (row, Some(right_row))))
+            }
+            None if join_type == JoinType::Left => indices.push((row, None)),
+            None => {}
+        }
+    }
+
+    Ok(indices)
+}
+
+/// The non-key columns of `right` for the joined rows, sorted by name.
+///
+/// For left joins, all columns become nullable.
+pub(super) fn joined_columns<R: FeatureCollection>(
+    right: &R,
+    on: &[&str],
+    right_indices: &[Option<usize>],
+    join_type: JoinType,
+) -> Result<Vec<(String, FeatureData)>> {
+    let mut columns: Vec<&String> = right
+        .column_types()
+        .keys()
+        .filter(|column| !on.contains(&column.as_str()))
+        .collect();
+    columns.sort_unstable();
+
+    columns
+        .into_iter()
+        .map(|column| {
+            let values = helpers::feature_data_from_ref(&right.data(column)?, right.len())?;
+            Ok((
+                column.clone(),
+                gather_feature_data(values, right_indices, join_type == JoinType::Left),
+            ))
+        })
+        .collect()
+}
+
+/// Select the values at `indices`, where `None` becomes null.
+/// The result is nullable if `nullable` is set or the input is nullable.
+pub(super) fn gather_feature_data(
+    data: FeatureData,
+    indices: &[Option<usize>],
+    nullable: bool,
+) -> FeatureData {
+    fn gather<T: Clone>(values: &[T], indices: &[Option<usize>]) -> Vec<T> {
+        indices
+            .iter()
+            .map(|index| values[index.expect("only left joins have unmatched rows")].clone())
+            .collect()
+    }
+
+    fn gather_nullable<T: Clone>(values: &[T], indices: &[Option<usize>]) -> Vec<Option<T>> {
+        indices
+            .iter()
+            .map(|index| index.map(|index| values[index].clone()))
+            .collect()
+    }
+
+    fn gather_nulls<T: Clone>(values: &[Option<T>], indices: &[Option<usize>]) -> Vec<Option<T>> {
+        indices
+            .iter()
+            .map(|index| index.and_then(|index| values[index].clone()))
+            .collect()
+    }
+
+    match data {
+        FeatureData::Text(values) if nullable => {
+            FeatureData::NullableText(gather_nullable(&values, indices))
+        }
+        FeatureData::Text(values) => FeatureData::Text(gather(&values, indices)),
+        FeatureData::NullableText(values) => {
+            FeatureData::NullableText(gather_nulls(&values, indices))
+        }
+        FeatureData::Number(values) if nullable => {
+            FeatureData::NullableNumber(gather_nullable(&values, indices))
+        }
+        FeatureData::Number(values) => FeatureData::Number(gather(&values, indices)),
+        FeatureData::NullableNumber(values) => {
+            FeatureData::NullableNumber(gather_nulls(&values, indices))
+        }
+        FeatureData::Decimal(values) if nullable => {
+            FeatureData::NullableDecimal(gather_nullable(&values, indices))
+        }
+        FeatureData::Decimal(values) => FeatureData::Decimal(gather(&values, indices)),
+        FeatureData::NullableDecimal(values) => {
+            FeatureData::NullableDecimal(gather_nulls(&values, indices))
+        }
+        FeatureData::Categorical(values) if nullable => {
+            FeatureData::NullableCategorical(gather_nullable(&values, indices))
+        }
+        FeatureData::Categorical(values) => FeatureData::Categorical(gather(&values, indices)),
+        FeatureData::NullableCategorical(values) => {
+            FeatureData::NullableCategorical(gather_nulls(&values, indices))
+        }
+        FeatureData::Bool(values) if nullable => {
+            FeatureData::NullableBool(gather_nullable(&values, indices))
+        }
+        FeatureData::Bool(values) => FeatureData::Bool(gather(&values, indices)),
+        FeatureData::NullableBool(values) => {
+            FeatureData::NullableBool(gather_nulls(&values, indices))
+        }
+    }
+}
+
+/// The type of a column regardless of its nullability
+fn non_nullable(data_type: FeatureDataType) -> FeatureDataType {
+    match data_type {
+        FeatureDataType::NullableText => FeatureDataType::Text,
+        FeatureDataType::NullableNumber => FeatureDataType::Number,
+        FeatureDataType::NullableDecimal => FeatureDataType::Decimal,
+        FeatureDataType::NullableCategorical => FeatureDataType::Categorical,
+        FeatureDataType::NullableBool => FeatureDataType::Bool,
+        data_type => data_type,
+    }
+}
@@ -1,5 +1,35 @@
+mod byte_size;
+mod chunks;
+mod csv_io;
+mod data_collection;
 mod feature_collection;
+mod geo_json;
+mod group_by;
+mod helpers;
+mod ipc;
+mod join;
+mod multi_line_string_collection;
 mod multi_point_collection;
+mod multi_polygon_collection;
+mod sort;
+mod typed_feature_collection;
+mod well_known;
 
+pub use byte_size::ByteSizeCounter;
+pub use chunks::ChunkLimit;
+pub use csv_io::CsvColumns;
+pub use data_collection::DataCollection;
 pub use feature_collection::FeatureCollection;
-pub use multi_point_collection::{MultiPointCollection, MultiPointCollectionBuilder};
+pub use geo_json::GeoJsonTimeProperties;
+pub use group_by::{Aggregate, AggregateFunction, ColumnReduction, TimeMerge};
+pub use ipc::ArrowIpcFormat;
+pub use join::JoinType;
+pub use multi_line_string_collection::{
+    MultiLineStringCollection, MultiLineStringCollectionBuilder,
+};
+pub use multi_point_collection::{
+    MultiPointCollection, MultiPointCollectionBuilder, MultiPointFeature,
+};
+pub use multi_polygon_collection::{MultiPolygonCollection, MultiPolygonCollectionBuilder};
+pub use sort::{SortKey, SortOptions};
+pub use typed_feature_collection::{TypedFeatureCollection, VectorDataType};
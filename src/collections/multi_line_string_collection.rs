@@ -0,0 +1,1302 @@
+use std::collections::HashMap;
+
+use arrow::array::{
+    Array, ArrayBuilder, ArrayRef, BooleanArray, Date64Builder, FixedSizeListArray,
+    FixedSizeListBuilder, Float64Array, Float64Builder, ListArray, ListBuilder, StructArray,
+    StructBuilder,
+};
+use arrow::datatypes::{DataType, Field};
+use snafu::ensure;
+
+use crate::collections::{helpers, ByteSizeCounter, FeatureCollection};
+use crate::error;
+use crate::operations::{Filterable, Takeable};
+use crate::primitives::{
+    Coordinate2D, FeatureData, FeatureDataRef, FeatureDataType, FeatureDataValue, TimeInterval,
+};
+use crate::util::arrow::downcast_array;
+use crate::util::Result;
+use std::mem;
+use std::slice;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct MultiLineStringCollection {
+    data: StructArray,
+    types: HashMap<String, FeatureDataType>,
+}
+
+impl Clone for MultiLineStringCollection {
+    /// Clone the MultiLineStringCollection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    ///
+    /// let lc = MultiLineStringCollection::empty();
+    /// let cloned = lc.clone();
+    ///
+    /// assert_eq!(lc.len(), 0);
+    /// assert_eq!(cloned.len(), 0);
+    /// ```
+    ///
+    fn clone(&self) -> Self {
+        Self {
+            data: StructArray::from(self.data.data()),
+            types: self.types.clone(),
+        }
+    }
+}
+
+impl MultiLineStringCollection {
+    /// Retrieve the composite arrow data type for multi line strings
+    pub(self) fn multi_line_strings_data_type() -> DataType {
+        DataType::List(
+            DataType::List(DataType::FixedSizeList(DataType::Float64.into(), 2).into()).into(),
+        )
+    }
+
+    /// Retrieve the composite arrow data type for time intervals
+    pub(self) fn time_data_type() -> DataType {
+        helpers::time_data_type()
+    }
+
+    /// Create an empty MultiLineStringCollection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    ///
+    /// let lc = MultiLineStringCollection::empty();
+    ///
+    /// assert_eq!(lc.len(), 0);
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            data: {
+                let columns = vec![
+                    Field::new(
+                        Self::FEATURE_COLUMN_NAME,
+                        Self::multi_line_strings_data_type(),
+                        false,
+                    ),
+                    Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
+                ];
+
+                helpers::empty_struct_array(columns)
+            },
+            types: Default::default(),
+        }
+    }
+
+    /// Use a builder for creating the line string collection
+    pub fn builder() -> MultiLineStringCollectionBuilder {
+        Default::default()
+    }
+
+    /// Create a line string collection from data
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData};
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into()], vec![(4., 4.).into(), (5., 5.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert_eq!(lc.len(), 2);
+    /// ```
+    pub fn from_data(
+        coordinates: Vec<Vec<Vec<Coordinate2D>>>,
+        time_intervals: Vec<TimeInterval>,
+        data: HashMap<String, FeatureData>,
+    ) -> Result<Self> {
+        let capacity = coordinates.len();
+
+        ensure!(
+            time_intervals.len() == capacity,
+            error::FeatureCollection {
+                details: "Number of time intervals must match the number of line strings"
+            }
+        );
+        ensure!(
+            coordinates
+                .iter()
+                .flatten()
+                .all(|line_string| is_valid_line_string(line_string)),
+            error::FeatureCollection {
+                details: "A line string must consist of at least two coordinates"
+            }
+        );
+
+        let mut columns = vec![
+            Field::new(
+                Self::FEATURE_COLUMN_NAME,
+                Self::multi_line_strings_data_type(),
+                false,
+            ),
+            Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
+        ];
+
+        let mut builders: Vec<Box<dyn ArrayBuilder>> = vec![
+            Box::new({
+                let mut builder = ListBuilder::new(ListBuilder::new(FixedSizeListBuilder::new(
+                    Float64Builder::new(2),
+                    2,
+                )));
+                for multi_line_string in coordinates {
+                    let line_string_builder = builder.values();
+                    for line_string in multi_line_string {
+                        MultiLineStringCollectionBuilder::append_single_line_string_to_builder(
+                            line_string_builder,
+                            line_string,
+                        )?;
+                    }
+                    builder.append(true)?;
+                }
+
+                builder
+            }),
+            Box::new(helpers::time_intervals_builder(&time_intervals)?),
+        ];
+
+        let mut data_types = HashMap::with_capacity(data.len());
+
+        for (name, feature_data) in data {
+            ensure!(
+                !Self::is_reserved_name(&name),
+                error::ColumnNameConflict { name }
+            );
+
+            ensure!(
+                feature_data.len() == capacity,
+                error::FeatureCollection {
+                    details: format!(
+                        "Length of column {} must match the number of line strings",
+                        name
+                    )
+                }
+            );
+
+            let column = Field::new(
+                &name,
+                feature_data.arrow_data_type(),
+                feature_data.nullable(),
+            );
+
+            columns.push(column);
+            builders.push(feature_data.arrow_builder()?);
+
+            data_types.insert(name, FeatureDataType::from(&feature_data));
+        }
+
+        if capacity == 0 {
+            return Ok(Self {
+                data: helpers::empty_struct_array(columns),
+                types: data_types,
+            });
+        }
+
+        let mut struct_builder = StructBuilder::new(columns, builders);
+        for _ in 0..capacity {
+            struct_builder.append(true)?;
+        }
+
+        Ok(Self {
+            data: struct_builder.finish(),
+            types: data_types,
+        })
+    }
+
+    fn features(&self) -> &ListArray {
+        let features_ref = self
+            .data
+            .column_by_name(Self::FEATURE_COLUMN_NAME)
+            .expect("There must exist a feature column");
+        downcast_array(features_ref)
+    }
+
+    /// Retrieves the coordinates of all line strings of this collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into()], vec![(4., 4.).into(), (5., 5.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let coords = lc.coordinates();
+    ///
+    /// assert_eq!(coords.len(), 6);
+    /// assert_eq!(
+    ///     coords,
+    ///     &[(0., 0.).into(), (1., 1.).into(), (2., 2.).into(), (3., 3.).into(), (4., 4.).into(), (5., 5.).into()]
+    /// );
+    /// ```
+    ///
+    pub fn coordinates(&self) -> &[Coordinate2D] {
+        let line_strings_ref = self.features().values();
+        let line_strings: &ListArray = downcast_array(&line_strings_ref);
+
+        let coordinates_ref = line_strings.values();
+        let coordinates: &FixedSizeListArray = downcast_array(&coordinates_ref);
+
+        let number_of_coordinates = coordinates.data().len();
+
+        let floats_ref = coordinates.values();
+        let floats: &Float64Array = downcast_array(&floats_ref);
+
+        unsafe {
+            slice::from_raw_parts(
+                floats.raw_values() as *const Coordinate2D,
+                number_of_coordinates,
+            )
+        }
+    }
+
+    /// Retrieves the offsets of the line strings into `coordinates()`.
+    /// The line string `i` consists of the coordinates `offsets[i]..offsets[i + 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into(), (4., 4.).into()], vec![(5., 5.).into(), (6., 6.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(lc.line_string_offsets(), &[0, 2, 5, 7]);
+    /// ```
+    ///
+    pub fn line_string_offsets(&self) -> &[i32] {
+        let line_strings = &self.features().data_ref().child_data()[0];
+
+        &line_strings.buffers()[0].typed_data::<i32>()
+            [line_strings.offset()..=line_strings.offset() + line_strings.len()]
+    }
+
+    /// Retrieves the offsets of the features into `line_string_offsets()`.
+    /// The feature `i` consists of the line strings `offsets[i]..offsets[i + 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into(), (4., 4.).into()], vec![(5., 5.).into(), (6., 6.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(lc.multi_line_string_offsets(), &[0, 1, 3]);
+    /// ```
+    ///
+    pub fn multi_line_string_offsets(&self) -> &[i32] {
+        let features = self.features();
+
+        &features.data_ref().buffers()[0].typed_data::<i32>()
+            [features.offset()..=features.offset() + features.len()]
+    }
+}
+
+impl FeatureCollection for MultiLineStringCollection {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// A line string collection is simple if every feature consists of exactly one line string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let simple = MultiLineStringCollection::from_data(
+    ///     vec![vec![vec![(0., 0.).into(), (1., 1.).into()]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert!(simple.is_simple());
+    ///
+    /// let multi = MultiLineStringCollection::from_data(
+    ///     vec![vec![vec![(0., 0.).into(), (1., 1.).into()], vec![(2., 2.).into(), (3., 3.).into()]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert!(!multi.is_simple());
+    /// ```
+    ///
+    fn is_simple(&self) -> bool {
+        self.multi_line_string_offsets()
+            .windows(2)
+            .all(|window| window[1] - window[0] == 1)
+    }
+
+    /// Retrieves a data column of this line string collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef, NullableDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("number_nulls".into(), FeatureData::NullableNumber(vec![Some(0.), None]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// if let FeatureDataRef::NullableNumber(numbers) = lc.data("number_nulls").unwrap() {
+    ///     assert_eq!(numbers.as_ref()[0], 0.);
+    ///     assert_eq!(numbers.nulls(), vec![false, true]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(lc.data("__features").is_err());
+    /// ```
+    ///
+    fn data(&self, column_name: &str) -> Result<FeatureDataRef> {
+        ensure!(
+            !Self::is_reserved_name(column_name),
+            error::FeatureCollection {
+                details: "Cannot access reserved columns via `data()` method"
+            }
+        );
+
+        helpers::feature_data_ref(&self.data, &self.types, column_name)
+    }
+
+    fn time_intervals(&self) -> &[TimeInterval] {
+        helpers::time_intervals_slice(&self.data, Self::TIME_COLUMN_NAME)
+    }
+
+    fn column_types(&self) -> &HashMap<String, FeatureDataType> {
+        &self.types
+    }
+
+    fn count_byte_size(&self, counter: &mut ByteSizeCounter) -> usize {
+        counter.count_struct_array(&self.data)
+    }
+
+    /// Extend the collection by an additional column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let extended = lc.add_column("foo", FeatureData::Decimal(vec![1, 2])).unwrap();
+    ///
+    /// if let FeatureDataRef::Decimal(decimals) = extended.data("foo").unwrap() {
+    ///     assert_eq!(decimals.as_ref(), &[1, 2]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(extended.add_column("foo", FeatureData::Decimal(vec![1, 2])).is_err());
+    /// assert!(extended.remove_column("foo").unwrap().data("foo").is_err());
+    /// ```
+    fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(new_column) && self.data.column_by_name(new_column).is_none(),
+            error::FeatureCollection {
+                details: "Cannot extend collection with name that is reserved or already in use"
+            }
+        );
+
+        ensure!(
+            data.len() == self.data.len(),
+            error::FeatureCollection {
+                details: "Length of new feature data column must match length of collection"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.insert(new_column.to_string(), FeatureDataType::from(&data));
+
+        Ok(Self {
+            data: helpers::struct_array_with_column(&self.data, new_column, &data)?,
+            types,
+        })
+    }
+
+    fn remove_column(&self, column: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+            error::FeatureCollection {
+                details: "Must not remove a non-existing or mandatory column"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.remove(column);
+
+        Ok(Self {
+            data: helpers::struct_array_without_column(&self.data, column),
+            types,
+        })
+    }
+
+    fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(old_name) && self.data.column_by_name(old_name).is_some(),
+            error::FeatureCollection {
+                details: "Must not rename a non-existing or mandatory column"
+            }
+        );
+        ensure!(
+            !Self::is_reserved_name(new_name) && self.data.column_by_name(new_name).is_none(),
+            error::ColumnNameConflict { name: new_name }
+        );
+
+        let mut types = self.types.clone();
+        if let Some(data_type) = types.remove(old_name) {
+            types.insert(new_name.to_string(), data_type);
+        }
+
+        Ok(Self {
+            data: helpers::struct_array_with_renamed_column(&self.data, old_name, new_name),
+            types,
+        })
+    }
+
+    fn select_columns(&self, columns: &[&str]) -> Result<Self> {
+        for &column in columns {
+            ensure!(
+                !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+                error::FeatureCollection {
+                    details: format!(
+                        "Cannot select the non-existing or mandatory column {}",
+                        column
+                    )
+                }
+            );
+        }
+
+        let types = self
+            .types
+            .iter()
+            .filter(|(column, _)| columns.contains(&column.as_str()))
+            .map(|(column, data_type)| (column.clone(), *data_type))
+            .collect();
+
+        Ok(Self {
+            data: helpers::struct_array_with_columns(&self.data, |column| {
+                Self::is_reserved_name(column) || columns.contains(&column)
+            }),
+            types,
+        })
+    }
+}
+
+impl Filterable for MultiLineStringCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use geoengine_datatypes::operations::Filterable;
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into()], vec![(4., 4.).into(), (5., 5.).into()]],
+    ///         vec![vec![(6., 6.).into(), (7., 7.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let filtered = lc.filter(vec![false, true, false]).unwrap();
+    ///
+    /// assert_eq!(filtered.len(), 1);
+    /// assert_eq!(filtered.coordinates(), &[(2., 2.).into(), (3., 3.).into(), (4., 4.).into(), (5., 5.).into()]);
+    /// assert_eq!(filtered.multi_line_string_offsets(), &[0, 2]);
+    /// assert_eq!(filtered.time_intervals(), &[TimeInterval::new_unchecked(1, 2)]);
+    /// ```
+    fn filter(&self, mask: Vec<bool>) -> Result<Self> {
+        ensure!(
+            mask.len() == self.data.len(),
+            error::MaskLengthDoesNotMatchCollectionLength {
+                mask_length: mask.len(),
+                collection_length: self.data.len(),
+            }
+        );
+
+        let filter_array: BooleanArray = mask.into();
+
+        Ok(Self {
+            data: helpers::filter_struct_array(
+                &self.data,
+                &filter_array,
+                Self::FEATURE_COLUMN_NAME,
+                |features, filter_array| {
+                    Ok(Arc::new(multi_line_strings_filter(
+                        downcast_array(features),
+                        filter_array,
+                    )?))
+                },
+                Self::TIME_COLUMN_NAME,
+            )?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+impl Takeable for MultiLineStringCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use std::collections::HashMap;
+    ///
+    /// let lc = MultiLineStringCollection::from_data(
+    ///     vec![
+    ///         vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 3.).into()], vec![(4., 4.).into(), (5., 5.).into()]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let taken = lc.take(&[1, 0]).unwrap();
+    ///
+    /// assert_eq!(taken.multi_line_string_offsets(), &[0, 2, 3]);
+    /// assert_eq!(taken.coordinates()[..2], lc.coordinates()[2..4]);
+    /// assert_eq!(taken.time_intervals(), &[TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(0, 1)]);
+    ///
+    /// let sliced = lc.slice(1, 1).unwrap();
+    ///
+    /// assert_eq!(sliced.multi_line_string_offsets(), &[0, 2]);
+    /// assert_eq!(sliced.coordinates(), &lc.coordinates()[2..]);
+    /// ```
+    fn take(&self, indices: &[usize]) -> Result<Self> {
+        Ok(Self {
+            data: helpers::take_struct_array(&self.data, indices)?,
+            types: self.types.clone(),
+        })
+    }
+
+    fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        Ok(Self {
+            data: helpers::slice_struct_array(&self.data, offset, len)?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+fn multi_line_strings_filter(
+    features: &ListArray,
+    filter_array: &BooleanArray,
+) -> Result<ListArray> {
+    let line_strings_ref: ArrayRef = features.values();
+    let line_strings: &ListArray = downcast_array(&line_strings_ref);
+
+    let coordinates_ref = line_strings.values();
+    let coordinates: &FixedSizeListArray = downcast_array(&coordinates_ref);
+
+    let floats_ref = coordinates.values();
+    let floats: &Float64Array = downcast_array(&floats_ref);
+
+    let mut new_features = ListBuilder::new(ListBuilder::new(FixedSizeListBuilder::new(
+        Float64Builder::new(2),
+        2,
+    )));
+
+    for feature_index in 0..features.len() {
+        if !filter_array.value(feature_index) {
+            continue;
+        }
+
+        let line_string_builder = new_features.values();
+
+        let first_line_string = features.value_offset(feature_index) as usize;
+        let last_line_string = features.value_offset(feature_index + 1) as usize;
+
+        for line_string_index in first_line_string..last_line_string {
+            let coordinate_builder = line_string_builder.values();
+
+            let first_coordinate = line_strings.value_offset(line_string_index) as usize;
+            let last_coordinate = line_strings.value_offset(line_string_index + 1) as usize;
+
+            for coordinate_index in first_coordinate..last_coordinate {
+                let float_builder = coordinate_builder.values();
+                float_builder.append_slice(floats.value_slice(2 * coordinate_index, 2))?;
+
+                coordinate_builder.append(true)?;
+            }
+
+            line_string_builder.append(true)?;
+        }
+
+        new_features.append(true)?;
+    }
+
+    Ok(new_features.finish())
+}
+
+/// A row-by-row builder for a line string collection
+pub struct MultiLineStringCollectionBuilder {
+    coordinates_builder: ListBuilder<ListBuilder<FixedSizeListBuilder<Float64Builder>>>,
+    time_intervals_builder: FixedSizeListBuilder<Date64Builder>,
+    builders: HashMap<String, Box<dyn ArrayBuilder>>,
+    types: HashMap<String, FeatureDataType>,
+    rows: usize,
+}
+
+impl Default for MultiLineStringCollectionBuilder {
+    /// Creates a builder for a line string collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    ///
+    /// let builder = MultiLineStringCollectionBuilder::default();
+    /// ```
+    ///
+    fn default() -> Self {
+        Self {
+            coordinates_builder: ListBuilder::new(ListBuilder::new(FixedSizeListBuilder::new(
+                Float64Builder::new(0),
+                2,
+            ))),
+            time_intervals_builder: FixedSizeListBuilder::new(Date64Builder::new(0), 2),
+            builders: Default::default(),
+            types: Default::default(),
+            rows: 0,
+        }
+    }
+}
+
+impl MultiLineStringCollectionBuilder {
+    /// Adds a column to the collection.
+    /// Must happen before data insertions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    /// use geoengine_datatypes::primitives::FeatureDataType;
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    ///
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    /// builder.add_column("__features", FeatureDataType::Number).unwrap_err();
+    /// ```
+    ///
+    pub fn add_column(&mut self, name: &str, data_type: FeatureDataType) -> Result<()> {
+        ensure!(
+            self.rows == 0,
+            error::FeatureCollectionBuilderException {
+                details: "It is not allowed to add further columns after data was inserted",
+            }
+        );
+        ensure!(
+            !MultiLineStringCollection::is_reserved_name(name) && !self.types.contains_key(name),
+            error::ColumnNameConflict {
+                name: name.to_string()
+            }
+        );
+
+        self.builders
+            .insert(name.into(), data_type.arrow_builder(0));
+        self.types.insert(name.into(), data_type);
+
+        Ok(())
+    }
+
+    /// Finishes a row and checks for completion
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    ///
+    /// builder.append_line_string(vec![(0.0, 0.0).into(), (1.0, 1.0).into()]).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_line_string(vec![(0.0, 0.0).into(), (1.0, 1.0).into()]).unwrap();
+    ///
+    /// builder.finish_row().unwrap_err();
+    /// ```
+    ///
+    pub fn finish_row(&mut self) -> Result<()> {
+        let rows = self.rows + 1;
+
+        ensure!(
+            self.coordinates_builder.len() == rows
+                && self.time_intervals_builder.len() == rows
+                && self.builders.values().all(|builder| builder.len() == rows),
+            error::FeatureCollectionBuilderException {
+                details: "Cannot finish row when child data is missing",
+            }
+        );
+
+        self.rows = rows;
+
+        Ok(())
+    }
+
+    /// Adds a single line string to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    ///
+    /// builder.append_line_string(vec![(0.0, 0.0).into()]).unwrap_err();
+    /// builder.append_line_string(vec![(0.0, 0.0).into(), (1.0, 1.0).into()]).unwrap();
+    /// builder.append_line_string(vec![(2.0, 2.0).into(), (3.0, 3.0).into()]).unwrap_err();
+    /// ```
+    ///
+    pub fn append_line_string(&mut self, coordinates: Vec<Coordinate2D>) -> Result<()> {
+        self.append_multi_line_string(vec![coordinates])
+    }
+
+    /// Adds a multi line string to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    ///
+    /// builder.append_multi_line_string(vec![
+    ///     vec![(0.0, 0.1).into(), (1.0, 1.1).into()],
+    ///     vec![(2.0, 2.1).into(), (3.0, 3.1).into()],
+    /// ]).unwrap();
+    /// builder.append_multi_line_string(vec![vec![(4.0, 4.1).into(), (5.0, 5.1).into()]]).unwrap_err();
+    /// ```
+    ///
+    pub fn append_multi_line_string(&mut self, line_strings: Vec<Vec<Coordinate2D>>) -> Result<()> {
+        ensure!(
+            self.coordinates_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another line string until row is finished",
+            }
+        );
+        ensure!(
+            line_strings
+                .iter()
+                .all(|line_string| is_valid_line_string(line_string)),
+            error::FeatureCollectionBuilderException {
+                details: "A line string must consist of at least two coordinates",
+            }
+        );
+
+        let line_string_builder = self.coordinates_builder.values();
+        for line_string in line_strings {
+            Self::append_single_line_string_to_builder(line_string_builder, line_string)?;
+        }
+
+        self.coordinates_builder.append(true)?;
+
+        Ok(())
+    }
+
+    fn append_single_line_string_to_builder(
+        line_string_builder: &mut ListBuilder<FixedSizeListBuilder<Float64Builder>>,
+        line_string: Vec<Coordinate2D>,
+    ) -> Result<()> {
+        let coordinate_builder = line_string_builder.values();
+        for coordinate in line_string {
+            let float_builder = coordinate_builder.values();
+            float_builder.append_value(coordinate.x)?;
+            float_builder.append_value(coordinate.y)?;
+
+            coordinate_builder.append(true)?;
+        }
+
+        line_string_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a time interval to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    ///
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap_err();
+    /// ```
+    ///
+    pub fn append_time_interval(&mut self, time_interval: TimeInterval) -> Result<()> {
+        ensure!(
+            self.time_intervals_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another time interval until row is finished",
+            }
+        );
+
+        let date_builder = self.time_intervals_builder.values();
+        date_builder.append_value(time_interval.start())?;
+        date_builder.append_value(time_interval.end())?;
+
+        self.time_intervals_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a data item to the current row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiLineStringCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureDataValue, FeatureDataType};
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    ///
+    /// builder.append_data("foobar", FeatureDataValue::Text("foo".into())).unwrap_err();
+    /// builder.append_data("foobar", FeatureDataValue::Number(0.)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(1.)).unwrap_err();
+    /// ```
+    ///
+    pub fn append_data(&mut self, column: &str, data: FeatureDataValue) -> Result<()> {
+        ensure!(
+            self.types.contains_key(column),
+            error::FeatureCollectionBuilderException {
+                details: format!("Column {} does not exist", column),
+            }
+        );
+
+        let data_builder = self.builders.get_mut(column).unwrap(); // previously checked
+
+        ensure!(
+            data_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another data item until row is finished",
+            }
+        );
+
+        let data_type = self.types.get(column).unwrap(); // previously checked
+
+        ensure!(
+            mem::discriminant(&FeatureDataType::from(&data)) == mem::discriminant(data_type), // same enum variant
+            error::FeatureCollectionBuilderException {
+                details: "Data type is wrong for the column",
+            }
+        );
+
+        helpers::append_feature_data_value(data_builder.as_mut(), data)
+    }
+
+    /// Builds the line string collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiLineStringCollectionBuilder, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataType, FeatureDataValue};
+    ///
+    /// let mut builder = MultiLineStringCollectionBuilder::default();
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    ///
+    /// builder.append_line_string(vec![(0.0, 0.1).into(), (1.0, 1.1).into()]).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(0.)).unwrap();
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_multi_line_string(vec![
+    ///     vec![(2.0, 2.1).into(), (3.0, 3.1).into()],
+    ///     vec![(4.0, 4.1).into(), (5.0, 5.1).into()],
+    /// ]).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(1.)).unwrap();
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// let line_string_collection = builder.build().unwrap();
+    ///
+    /// assert_eq!(line_string_collection.len(), 2);
+    /// assert_eq!(line_string_collection.coordinates().len(), 6);
+    /// assert_eq!(line_string_collection.line_string_offsets(), &[0, 2, 4, 6]);
+    /// assert_eq!(line_string_collection.multi_line_string_offsets(), &[0, 1, 3]);
+    /// ```
+    ///
+    pub fn build(mut self) -> Result<MultiLineStringCollection> {
+        ensure!(
+            self.coordinates_builder.len() == self.rows
+                && self.time_intervals_builder.len() == self.rows
+                && self
+                    .builders
+                    .values()
+                    .all(|builder| builder.len() == self.rows),
+            error::FeatureCollectionBuilderException {
+                details: "Cannot build a line string collection out of unfinished rows",
+            }
+        );
+
+        let mut columns = Vec::with_capacity(self.types.len() + 2);
+        let mut builders: Vec<Box<dyn ArrayBuilder>> = Vec::with_capacity(self.types.len() + 2);
+
+        columns.push(Field::new(
+            MultiLineStringCollection::FEATURE_COLUMN_NAME,
+            MultiLineStringCollection::multi_line_strings_data_type(),
+            false,
+        ));
+        builders.push(Box::new(self.coordinates_builder));
+
+        columns.push(Field::new(
+            MultiLineStringCollection::TIME_COLUMN_NAME,
+            MultiLineStringCollection::time_data_type(),
+            false,
+        ));
+        builders.push(Box::new(self.time_intervals_builder));
+
+        for (column_name, builder) in self.builders.drain() {
+            let column_type = self.types.get(&column_name).unwrap(); // column must exist
+            columns.push(Field::new(
+                &column_name,
+                column_type.arrow_data_type(),
+                column_type.nullable(),
+            ));
+            builders.push(builder);
+        }
+
+        if self.rows == 0 {
+            return Ok(MultiLineStringCollection {
+                data: helpers::empty_struct_array(columns),
+                types: self.types,
+            });
+        }
+
+        let mut struct_builder = StructBuilder::new(columns, builders);
+
+        for _ in 0..self.rows {
+            struct_builder.append(true)?;
+        }
+
+        Ok(MultiLineStringCollection {
+            data: struct_builder.finish(),
+            types: self.types,
+        })
+    }
+}
+
+/// A line string must consist of at least two coordinates
+fn is_valid_line_string(line_string: &[Coordinate2D]) -> bool {
+    line_string.len() >= 2
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let lc = MultiLineStringCollection::from_data(
+            vec![
+                vec![vec![(0., 0.).into(), (1., 1.).into()]],
+                vec![vec![(2., 2.).into(), (3., 3.).into()]],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(0, 1),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+                map
+            },
+        )
+        .unwrap();
+
+        let cloned = lc.clone();
+
+        assert_eq!(lc.len(), cloned.len());
+        assert_eq!(lc.coordinates(), cloned.coordinates());
+        assert_eq!(lc.line_string_offsets(), cloned.line_string_offsets());
+    }
+
+    #[test]
+    fn filter_keeps_attributes_aligned() {
+        let lc = MultiLineStringCollection::from_data(
+            vec![
+                vec![vec![(0., 0.).into(), (1., 1.).into()]],
+                vec![
+                    vec![(2., 2.).into(), (3., 3.).into()],
+                    vec![(4., 4.).into(), (5., 5.).into(), (6., 6.).into()],
+                ],
+                vec![vec![(7., 7.).into(), (8., 8.).into()]],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+                TimeInterval::new_unchecked(2, 3),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0., 1., 2.]));
+                map
+            },
+        )
+        .unwrap();
+
+        let filtered = lc.filter(vec![false, true, true]).unwrap();
+
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered.line_string_offsets(), &[0, 2, 5, 7]);
+        assert_eq!(filtered.multi_line_string_offsets(), &[0, 2, 3]);
+        assert_eq!(
+            filtered.coordinates(),
+            &[
+                (2., 2.).into(),
+                (3., 3.).into(),
+                (4., 4.).into(),
+                (5., 5.).into(),
+                (6., 6.).into(),
+                (7., 7.).into(),
+                (8., 8.).into(),
+            ]
+        );
+        assert_eq!(
+            filtered.time_intervals(),
+            &[
+                TimeInterval::new_unchecked(1, 2),
+                TimeInterval::new_unchecked(2, 3),
+            ]
+        );
+
+        if let FeatureDataRef::Number(numbers) = filtered.data("number").unwrap() {
+            assert_eq!(numbers.as_ref(), &[1., 2.]);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn builder_errors() {
+        let mut builder = MultiLineStringCollection::builder();
+        builder
+            .add_column("number", FeatureDataType::Number)
+            .unwrap();
+
+        assert!(builder
+            .append_multi_line_string(vec![
+                vec![(0., 0.).into(), (1., 1.).into()],
+                vec![(2., 2.).into()],
+            ])
+            .is_err());
+
+        builder
+            .append_line_string(vec![(0., 0.).into(), (1., 1.).into()])
+            .unwrap();
+        builder
+            .append_time_interval(TimeInterval::new_unchecked(0, 1))
+            .unwrap();
+
+        // the number is missing
+        assert!(builder.finish_row().is_err());
+        assert!(builder.build().is_err());
+    }
+
+    #[test]
+    fn from_data_errors() {
+        let line_string = vec![(0., 0.).into(), (1., 1.).into()];
+
+        assert!(MultiLineStringCollection::from_data(
+            vec![vec![line_string.clone(), vec![(2., 2.).into()]]],
+            vec![TimeInterval::default()],
+            HashMap::new(),
+        )
+        .is_err());
+        assert!(MultiLineStringCollection::from_data(
+            vec![vec![line_string.clone()]],
+            vec![TimeInterval::default(); 2],
+            HashMap::new(),
+        )
+        .is_err());
+        assert!(MultiLineStringCollection::from_data(
+            vec![vec![line_string]],
+            vec![TimeInterval::default()],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+                map
+            },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn empty_input() {
+        let from_data = MultiLineStringCollection::from_data(vec![], vec![], {
+            let mut map = HashMap::new();
+            map.insert("number".into(), FeatureData::Number(vec![]));
+            map
+        })
+        .unwrap();
+
+        let mut builder = MultiLineStringCollection::builder();
+        builder
+            .add_column("number", FeatureDataType::Number)
+            .unwrap();
+        let built = builder.build().unwrap();
+
+        for collection in &[from_data, built] {
+            assert!(collection.is_empty());
+            assert!(collection.coordinates().is_empty());
+            assert_eq!(collection.line_string_offsets(), &[0]);
+            assert_eq!(collection.multi_line_string_offsets(), &[0]);
+            assert!(collection.time_intervals().is_empty());
+            assert!(collection.data("number").is_ok());
+
+            assert!(collection.filter(vec![]).unwrap().is_empty());
+        }
+
+        assert_eq!(
+            MultiLineStringCollection::empty().line_string_offsets(),
+            &[0]
+        );
+    }
+
+    #[test]
+    fn filter_everything_or_nothing() {
+        let lc = MultiLineStringCollection::from_data(
+            vec![
+                vec![vec![(0., 0.).into(), (1., 1.).into()]],
+                vec![vec![(2., 2.).into(), (3., 3.).into(), (4., 4.).into()]],
+            ],
+            vec![TimeInterval::default(); 2],
+            HashMap::new(),
+        )
+        .unwrap();
+
+        let everything = lc.filter(vec![true; 2]).unwrap();
+
+        assert_eq!(everything.coordinates(), lc.coordinates());
+        assert_eq!(everything.line_string_offsets(), &[0, 2, 5]);
+        assert_eq!(everything.multi_line_string_offsets(), &[0, 1, 2]);
+
+        let nothing = lc.filter(vec![false; 2]).unwrap();
+
+        assert!(nothing.is_empty());
+        assert!(nothing.coordinates().is_empty());
+        assert_eq!(nothing.multi_line_string_offsets(), &[0]);
+    }
+
+    #[test]
+    fn offsets_of_slices() {
+        let lc = MultiLineStringCollection::from_data(
+            vec![
+                vec![vec![(0., 0.).into(), (1., 1.).into()]],
+                vec![
+                    vec![(2., 2.).into(), (3., 3.).into()],
+                    vec![(4., 4.).into(), (5., 5.).into(), (6., 6.).into()],
+                ],
+                vec![vec![(7., 7.).into(), (8., 8.).into()]],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+                TimeInterval::new_unchecked(2, 3),
+            ],
+            HashMap::new(),
+        )
+        .unwrap();
+
+        let sliced = lc.slice(1, 2).unwrap();
+
+        assert_eq!(sliced.line_string_offsets(), &[0, 2, 5, 7]);
+        assert_eq!(sliced.multi_line_string_offsets(), &[0, 2, 3]);
+        assert_eq!(sliced.coordinates(), &lc.coordinates()[2..]);
+
+        let sliced = sliced.slice(1, 1).unwrap();
+
+        assert_eq!(sliced.line_string_offsets(), &[0, 2]);
+        assert_eq!(sliced.multi_line_string_offsets(), &[0, 1]);
+        assert_eq!(sliced.coordinates(), &[(7., 7.).into(), (8., 8.).into()]);
+        assert_eq!(
+            sliced.time_intervals(),
+            &[TimeInterval::new_unchecked(2, 3)]
+        );
+
+        let sliced = lc.slice(3, 0).unwrap();
+
+        assert!(sliced.is_empty());
+        assert_eq!(sliced.line_string_offsets(), &[0]);
+        assert_eq!(sliced.multi_line_string_offsets(), &[0]);
+    }
+}
@@ -1,25 +1,35 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use arrow::array::{
-    Array, ArrayBuilder, ArrayData, ArrayRef, BooleanArray, Date64Array, Date64Builder,
-    FixedSizeListArray, FixedSizeListBuilder, Float64Array, Float64Builder, Int64Array,
-    Int64Builder, ListArray, ListBuilder, StringArray, StringBuilder, StructArray, StructBuilder,
-    UInt8Array, UInt8Builder,
+    Array, ArrayBuilder, BooleanArray, Date64Builder, FixedSizeListArray, FixedSizeListBuilder,
+    Float64Array, Float64Builder, ListArray, ListBuilder, StructArray, StructBuilder,
 };
-use arrow::compute::kernels::filter::filter;
-use arrow::datatypes::{DataType, DateUnit, Field};
+use arrow::datatypes::{DataType, Field};
+use float_cmp::{ApproxEq, F64Margin};
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::json;
 use snafu::ensure;
 
-use crate::collections::FeatureCollection;
+use crate::collections::chunks::{self, ChunkLimit};
+use crate::collections::csv_io::{self, CsvColumns};
+use crate::collections::geo_json::{self, GeoJsonTimeProperties};
+use crate::collections::group_by::{self, Aggregate, ColumnReduction, TimeMerge};
+use crate::collections::ipc::{self, ArrowIpcFormat};
+use crate::collections::join::{self, JoinType};
+use crate::collections::sort::{self, SortOptions};
+use crate::collections::well_known;
+use crate::collections::{helpers, ByteSizeCounter, FeatureCollection};
 use crate::error;
-use crate::operations::Filterable;
+use crate::operations::{Filterable, Takeable};
 use crate::primitives::{
-    CategoricalDataRef, Coordinate2D, DecimalDataRef, FeatureData, FeatureDataRef, FeatureDataType,
-    FeatureDataValue, NullableCategoricalDataRef, NullableDecimalDataRef, NullableNumberDataRef,
-    NullableTextDataRef, NumberDataRef, TextDataRef, TimeInterval,
+    BoundingBox2D, Coordinate2D, FeatureData, FeatureDataRef, FeatureDataType, FeatureDataValue,
+    TimeInterval,
 };
-use crate::util::arrow::{downcast_array, downcast_mut_array};
+use crate::util::arrow::downcast_array;
 use crate::util::Result;
+use std::io::{Read, Write};
 use std::mem;
 use std::slice;
 use std::sync::Arc;
@@ -61,7 +71,7 @@ impl MultiPointCollection {
 
     /// Retrieve the composite arrow data type for multi points
     pub(self) fn time_data_type() -> DataType {
-        DataType::FixedSizeList(DataType::Date64(DateUnit::Millisecond).into(), 2)
+        helpers::time_data_type()
     }
 
     /// Create an empty MultiPointCollection.
@@ -87,7 +97,7 @@ impl MultiPointCollection {
                     Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
                 ];
 
-                StructArray::from(ArrayData::builder(DataType::Struct(columns)).len(0).build())
+                helpers::empty_struct_array(columns)
             },
             types: Default::default(),
         }
@@ -152,17 +162,7 @@ impl MultiPointCollection {
 
                 builder
             }),
-            Box::new({
-                let mut builder = FixedSizeListBuilder::new(Date64Builder::new(capacity), 2);
-                for time_interval in time_intervals {
-                    let date_builder = builder.values();
-                    date_builder.append_value(time_interval.start())?;
-                    date_builder.append_value(time_interval.end())?;
-                    builder.append(true)?;
-                }
-
-                builder
-            }),
+            Box::new(helpers::time_intervals_builder(&time_intervals)?),
         ];
 
         let mut data_types = HashMap::with_capacity(data.len());
@@ -185,6 +185,13 @@ impl MultiPointCollection {
             data_types.insert(name, FeatureDataType::from(&feature_data));
         }
 
+        if capacity == 0 {
+            return Ok(Self {
+                data: helpers::empty_struct_array(columns),
+                types: data_types,
+            });
+        }
+
         let mut struct_builder = StructBuilder::new(columns, builders);
         for _ in 0..capacity {
             struct_builder.append(true)?;
@@ -246,878 +253,3673 @@ impl MultiPointCollection {
         }
     }
 
-    fn array_refs_of_reserved_fields(&self) -> Vec<ArrayRef> {
-        vec![
-            self.data
-                .column_by_name(Self::FEATURE_COLUMN_NAME)
-                .unwrap()
-                .clone(),
-            self.data
-                .column_by_name(Self::TIME_COLUMN_NAME)
-                .unwrap()
-                .clone(),
-        ]
-    }
-}
-
-impl FeatureCollection for MultiPointCollection {
-    fn len(&self) -> usize {
-        self.data.len()
-    }
+    /// Retrieves the offsets of the features into `coordinates()`.
+    /// The feature `i` consists of the coordinates `offsets[i]..offsets[i + 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.multi_point_offsets(), &[0, 1, 3]);
+    /// ```
+    ///
+    pub fn multi_point_offsets(&self) -> &[i32] {
+        let features_ref = self
+            .data
+            .column_by_name(Self::FEATURE_COLUMN_NAME)
+            .expect("There must exist a feature column");
+        let features: &ListArray = downcast_array(features_ref);
 
-    fn is_simple(&self) -> bool {
-        self.len() == self.coordinates().len()
+        &features.data_ref().buffers()[0].typed_data::<i32>()
+            [features.offset()..=features.offset() + features.len()]
     }
 
-    /// Retrieves a data column of this point collection
+    /// Iterates over the features of this point collection without copying any columns
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
-    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData, FeatureDataRef, NullableDataRef};
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataValue, TimeInterval};
     /// use std::collections::HashMap;
     ///
     /// let pc = MultiPointCollection::from_data(
-    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
-    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
     ///     {
     ///         let mut map = HashMap::new();
-    ///         map.insert("numbers".into(), FeatureData::Number(vec![0., 1., 2.]));
-    ///         map.insert("number_nulls".into(), FeatureData::NullableNumber(vec![Some(0.), None, Some(2.)]));
+    ///         map.insert("foo".into(), FeatureData::NullableText(vec![Some("a".into()), None]));
     ///         map
     ///     },
     /// ).unwrap();
     ///
-    /// assert_eq!(pc.len(), 3);
-    ///
-    /// if let FeatureDataRef::Number(numbers) = pc.data("numbers").unwrap() {
-    ///     assert_eq!(numbers.as_ref(), &[0., 1., 2.]);
-    /// } else {
-    ///     unreachable!();
-    /// }
+    /// let features: Vec<_> = pc.features().collect();
     ///
-    /// if let FeatureDataRef::NullableNumber(numbers) = pc.data("number_nulls").unwrap() {
-    ///     assert_eq!(numbers.as_ref()[0], 0.);
-    ///     assert_eq!(numbers.as_ref()[2], 2.);
-    ///     assert_eq!(numbers.nulls(), vec![false, true, false]);
+    /// assert_eq!(features.len(), 2);
+    /// assert_eq!(features[1].coordinates(), &[(1., 1.).into(), (2., 2.).into()]);
+    /// assert_eq!(features[1].time_interval(), TimeInterval::new_unchecked(1, 2));
+    /// if let FeatureDataValue::NullableText(text) = features[0].data("foo").unwrap() {
+    ///     assert_eq!(text, Some("a".to_string()));
     /// } else {
     ///     unreachable!();
     /// }
+    /// assert!(features[0].data("bar").is_err());
     /// ```
     ///
-    fn data(&self, column_name: &str) -> Result<FeatureDataRef> {
-        ensure!(
-            !Self::is_reserved_name(column_name),
-            error::FeatureCollection {
-                details: "Cannot access reserved columns via `data()` method"
-            }
-        );
-
-        let column = self.data.column_by_name(column_name);
-
-        ensure!(
-            column.is_some(),
-            error::FeatureCollection {
-                details: format!(
-                    "The column {} does not exist in the point collection",
-                    column_name
-                )
-            }
-        );
-
-        let column = column.unwrap(); // previously checked
-
-        Ok(match self.types.get(column_name).unwrap() {
-            // previously checked
-            FeatureDataType::Number => {
-                let array: &Float64Array = downcast_array(column);
-                NumberDataRef::new(array.values()).into()
-            }
-            FeatureDataType::NullableNumber => {
-                let array: &Float64Array = downcast_array(column);
-                NullableNumberDataRef::new(array.values(), array.data_ref().null_bitmap()).into()
-            }
-            FeatureDataType::Text => {
-                let array: &StringArray = downcast_array(column);
-                TextDataRef::new(array.value_data(), array.value_offsets()).into()
-            }
-            FeatureDataType::NullableText => {
-                let array: &StringArray = downcast_array(column);
-                NullableTextDataRef::new(array.value_data(), array.value_offsets()).into()
-            }
-            FeatureDataType::Decimal => {
-                let array: &Int64Array = downcast_array(column);
-                DecimalDataRef::new(array.values()).into()
-            }
-            FeatureDataType::NullableDecimal => {
-                let array: &Int64Array = downcast_array(column);
-                NullableDecimalDataRef::new(array.values(), array.data_ref().null_bitmap()).into()
-            }
-            FeatureDataType::Categorical => {
-                let array: &UInt8Array = downcast_array(column);
-                CategoricalDataRef::new(array.values()).into()
-            }
-            FeatureDataType::NullableCategorical => {
-                let array: &UInt8Array = downcast_array(column);
-                NullableCategoricalDataRef::new(array.values(), array.data_ref().null_bitmap())
-                    .into()
-            }
+    pub fn features(&self) -> impl Iterator<Item = MultiPointFeature<'_>> {
+        (0..self.len()).map(move |index| MultiPointFeature {
+            collection: self,
+            index,
         })
     }
 
-    /// Retrieves the time intervals of this point collection
+    /// Writes the point collection as a GeoJSON `FeatureCollection` into `writer`.
+    ///
+    /// Features with a single coordinate become `Point`s, all others `MultiPoint`s.
+    /// All data columns are written as properties, where null values become `null`.
+    /// The time interval is written as the property `__time` with the fields `start` and `end` in ms.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
-    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData};
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData};
     /// use std::collections::HashMap;
     ///
     /// let pc = MultiPointCollection::from_data(
-    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
-    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
-    ///     HashMap::new(),
+    ///     vec![vec![(0., 0.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::NullableNumber(vec![None]));
+    ///         map
+    ///     },
     /// ).unwrap();
     ///
-    /// assert_eq!(pc.len(), 3);
-    ///
-    /// let time_intervals = pc.time_intervals();
+    /// let mut buffer = Vec::new();
+    /// pc.write_geo_json(&mut buffer).unwrap();
     ///
-    /// assert_eq!(time_intervals.len(), 3);
     /// assert_eq!(
-    ///     time_intervals,
-    ///     &[TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)]
+    ///     serde_json::from_slice::<serde_json::Value>(&buffer).unwrap(),
+    ///     serde_json::json!({
+    ///         "type": "FeatureCollection",
+    ///         "features": [{
+    ///             "type": "Feature",
+    ///             "geometry": {"type": "Point", "coordinates": [0.0, 0.0]},
+    ///             "properties": {"__time": {"start": 0, "end": 1}, "foo": null},
+    ///         }],
+    ///     })
     /// );
     /// ```
-    ///
-    fn time_intervals(&self) -> &[TimeInterval] {
-        let features_ref = self
-            .data
-            .column_by_name(Self::TIME_COLUMN_NAME)
-            .expect("There must exist a time interval column");
-        let features: &FixedSizeListArray = downcast_array(features_ref);
-
-        let number_of_time_intervals = self.len();
-
-        let timestamps_ref = features.values();
-        let timestamps: &Date64Array = downcast_array(&timestamps_ref);
-
-        unsafe {
-            slice::from_raw_parts(
-                timestamps.raw_values() as *const TimeInterval,
-                number_of_time_intervals,
-            )
-        }
+    pub fn write_geo_json<W: Write>(&self, writer: W) -> Result<()> {
+        let mut column_names: Vec<&str> = self.types.keys().map(String::as_str).collect();
+        column_names.sort_unstable();
+
+        let columns = column_names
+            .into_iter()
+            .map(|column| {
+                Ok((
+                    column,
+                    geo_json::json_values(&self.data(column)?, self.len())?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let coordinates = self.coordinates();
+        let geometries = self.multi_point_offsets().windows(2).map(|window| {
+            let feature_coordinates = &coordinates[window[0] as usize..window[1] as usize];
+            if let [coordinate] = feature_coordinates {
+                json!({
+                    "type": "Point",
+                    "coordinates": [coordinate.x, coordinate.y],
+                })
+            } else {
+                json!({
+                    "type": "MultiPoint",
+                    "coordinates": feature_coordinates
+                        .iter()
+                        .map(|coordinate| [coordinate.x, coordinate.y])
+                        .collect::<Vec<_>>(),
+                })
+            }
+        });
+
+        geo_json::write_feature_collection(
+            writer,
+            geometries,
+            self.time_intervals(),
+            Self::TIME_COLUMN_NAME,
+            &columns,
+        )
     }
 
-    /// Extend the collection by an additional column
+    /// Serializes the point collection as a GeoJSON `FeatureCollection` string.
+    ///
+    /// See `write_geo_json` for details on the layout.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
-    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataType, TimeInterval, FeatureDataValue, FeatureDataRef};
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData};
+    /// use std::collections::HashMap;
     ///
-    /// let collection = {
-    ///     let mut builder = MultiPointCollection::builder();
-    ///     builder.add_column("foo", FeatureDataType::Number);
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into(), (1., 1.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("name".into(), FeatureData::Text(vec!["foo".into()]));
+    ///         map
+    ///     },
+    /// ).unwrap();
     ///
-    ///     builder.append_coordinate((0., 0.).into());
-    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
-    ///     builder.append_data("foo", FeatureDataValue::Number(0.));
-    ///     builder.finish_row();
+    /// assert_eq!(
+    ///     serde_json::from_str::<serde_json::Value>(&pc.to_geo_json().unwrap()).unwrap(),
+    ///     serde_json::json!({
+    ///         "type": "FeatureCollection",
+    ///         "features": [{
+    ///             "type": "Feature",
+    ///             "geometry": {"type": "MultiPoint", "coordinates": [[0.0, 0.0], [1.0, 1.0]]},
+    ///             "properties": {"__time": {"start": 0, "end": 1}, "name": "foo"},
+    ///         }],
+    ///     })
+    /// );
     ///
-    ///     builder.append_coordinate((1., 1.).into());
-    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
-    ///     builder.append_data("foo", FeatureDataValue::Number(1.));
-    ///     builder.finish_row();
+    /// assert_eq!(
+    ///     MultiPointCollection::empty().to_geo_json().unwrap(),
+    ///     r#"{"type":"FeatureCollection","features":[]}"#
+    /// );
+    /// ```
+    pub fn to_geo_json(&self) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.write_geo_json(&mut buffer)?;
+
+        // serde_json only produces valid UTF-8
+        Ok(String::from_utf8(buffer).expect("GeoJSON must be valid UTF-8"))
+    }
+
+    /// Reads a point collection from a GeoJSON `FeatureCollection` of `Point`s and `MultiPoint`s.
     ///
-    ///     builder.build().unwrap()
-    /// };
+    /// The data type of each property is inferred from its values.
+    /// Properties that are missing or `null` for some features become nullable columns.
+    /// The time intervals are read from the properties given by `time_properties`.
     ///
-    /// assert_eq!(collection.len(), 2);
+    /// # Examples
     ///
-    /// let extended_collection = collection.add_column("bar", FeatureData::Number(vec![2., 4.])).unwrap();
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection, GeoJsonTimeProperties};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataRef, NullableDataRef};
+    ///
+    /// let geo_json = r#"{
+    ///     "type": "FeatureCollection",
+    ///     "features": [
+    ///         {
+    ///             "type": "Feature",
+    ///             "geometry": {"type": "Point", "coordinates": [0.0, 0.1]},
+    ///             "properties": {"name": "foo", "value": 1, "from": 0, "to": 10}
+    ///         },
+    ///         {
+    ///             "type": "Feature",
+    ///             "geometry": {"type": "MultiPoint", "coordinates": [[1.0, 1.1], [2.0, 2.1]]},
+    ///             "properties": {"name": "bar", "from": 5, "to": 15}
+    ///         }
+    ///     ]
+    /// }"#;
+    ///
+    /// let pc = MultiPointCollection::from_geo_json(
+    ///     geo_json,
+    ///     &GeoJsonTimeProperties::new("from", "to"),
+    /// ).unwrap();
     ///
-    /// assert_eq!(extended_collection.len(), 2);
-    /// if let FeatureDataRef::Number(numbers) = extended_collection.data("foo").unwrap() {
-    ///     assert_eq!(numbers.as_ref(), &[0., 1.]);
-    /// } else {
-    ///     unreachable!();
-    /// }
-    /// if let FeatureDataRef::Number(numbers) = extended_collection.data("bar").unwrap() {
-    ///     assert_eq!(numbers.as_ref(), &[2., 4.]);
+    /// assert_eq!(pc.len(), 2);
+    /// assert_eq!(pc.coordinates(), &[(0.0, 0.1).into(), (1.0, 1.1).into(), (2.0, 2.1).into()]);
+    /// assert_eq!(pc.time_intervals(), &[TimeInterval::new_unchecked(0, 10), TimeInterval::new_unchecked(5, 15)]);
+    ///
+    /// if let FeatureDataRef::NullableDecimal(values) = pc.data("value").unwrap() {
+    ///     assert_eq!(values.nulls(), vec![false, true]);
     /// } else {
     ///     unreachable!();
     /// }
+    /// assert!(pc.data("from").is_err());
+    ///
+    /// let line_string = r#"{
+    ///     "type": "FeatureCollection",
+    ///     "features": [{
+    ///         "type": "Feature",
+    ///         "geometry": {"type": "LineString", "coordinates": [[0.0, 0.0], [1.0, 1.0]]},
+    ///         "properties": {}
+    ///     }]
+    /// }"#;
+    ///
+    /// assert!(MultiPointCollection::from_geo_json(line_string, &GeoJsonTimeProperties::default()).is_err());
     /// ```
-    fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self> {
-        ensure!(
-            !Self::is_reserved_name(new_column) && self.data.column_by_name(new_column).is_none(),
-            error::FeatureCollection {
-                details: "Cannot extend collection with name that is reserved or already in use"
-            }
-        );
+    pub fn from_geo_json(geo_json: &str, time_properties: &GeoJsonTimeProperties) -> Result<Self> {
+        let features =
+            geo_json::read_feature_collection(geo_json, time_properties, Self::TIME_COLUMN_NAME)?;
 
-        ensure!(
-            data.len() == self.data.len(),
-            error::FeatureCollection {
-                details: "Length of new feature data column must match length of collection"
-            }
-        );
+        if features.geometries.is_empty() {
+            return Ok(Self::empty());
+        }
 
-        let mut columns = vec![
-            Field::new(
-                Self::FEATURE_COLUMN_NAME,
-                Self::multi_points_data_type(),
-                false,
-            ),
-            Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
-        ];
-        let mut column_values: Vec<ArrayRef> = self.array_refs_of_reserved_fields();
+        let mut builder = Self::builder();
 
-        for (column_name, column_type) in &self.types {
-            columns.push(Field::new(
-                &column_name,
-                column_type.arrow_data_type(),
-                column_type.nullable(),
-            ));
-            column_values.push(self.data.column_by_name(&column_name).unwrap().clone());
+        for (column, data_type) in &features.columns {
+            builder.add_column(column, *data_type)?;
         }
 
-        columns.push(Field::new(
-            new_column,
-            data.arrow_data_type(),
-            data.nullable(),
-        ));
-        column_values.push(data.arrow_builder().map(|mut builder| builder.finish())?);
+        for (feature_index, geometry) in features.geometries.iter().enumerate() {
+            match geometry["type"].as_str() {
+                Some("Point") => {
+                    builder.append_coordinate(geo_json::coordinate(&geometry["coordinates"])?)?
+                }
+                Some("MultiPoint") => builder.append_multi_coordinate(
+                    geometry["coordinates"]
+                        .as_array()
+                        .ok_or_else(|| error::Error::GeoJson {
+                            details: "MultiPoint coordinates must be an array".to_string(),
+                        })?
+                        .iter()
+                        .map(geo_json::coordinate)
+                        .collect::<Result<_>>()?,
+                )?,
+                geometry_type => {
+                    return error::GeoJsonUnsupportedGeometry {
+                        geometry_type: geometry_type.unwrap_or("null"),
+                    }
+                    .fail()
+                }
+            }
 
-        let mut types = self.types.clone();
-        types.insert(new_column.to_string(), FeatureDataType::from(&data));
+            builder.append_time_interval(features.time_intervals[feature_index])?;
 
-        Ok(Self {
-            data: struct_array_from_data(columns, column_values, self.data.len()),
-            types,
-        })
+            for (column, data_type) in &features.columns {
+                builder.append_data(column, features.value(feature_index, column, *data_type)?)?;
+            }
+
+            builder.finish_row()?;
+        }
+
+        builder.build()
     }
 
-    /// Removes a column and returns an updated collection
+    /// Reads a point collection from CSV with a header row.
+    ///
+    /// The coordinates are taken from the `x`/`y` columns and the time intervals from the optional
+    /// start/end columns in ms, where missing values denote unbounded intervals.
+    /// All other columns become `Decimal`, `Number` or `Text` columns, depending on their values.
+    /// Columns with empty cells become nullable.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
-    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataType, TimeInterval, FeatureDataValue, FeatureDataRef};
-    ///
-    /// let collection = {
-    ///     let mut builder = MultiPointCollection::builder();
-    ///     builder.add_column("foo", FeatureDataType::Number);
-    ///
-    ///     builder.append_coordinate((0., 0.).into());
-    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
-    ///     builder.append_data("foo", FeatureDataValue::Number(0.));
-    ///     builder.finish_row();
+    /// use geoengine_datatypes::collections::{CsvColumns, MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataRef, NullableDataRef};
     ///
-    ///     builder.append_coordinate((1., 1.).into());
-    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
-    ///     builder.append_data("foo", FeatureDataValue::Number(1.));
-    ///     builder.finish_row();
+    /// let csv = "x,y,start,end,name,value\n\
+    ///            0.0,0.1,0,10,foo,1.5\n\
+    ///            1.0,1.1,5,15,bar,\n";
     ///
-    ///     builder.build().unwrap()
-    /// };
+    /// let pc = MultiPointCollection::from_csv(
+    ///     csv.as_bytes(),
+    ///     &CsvColumns::new("x", "y").with_time(Some("start"), Some("end")),
+    /// ).unwrap();
     ///
-    /// assert_eq!(collection.len(), 2);
-    /// assert!(collection.data("foo").is_ok());
+    /// assert_eq!(pc.coordinates(), &[(0.0, 0.1).into(), (1.0, 1.1).into()]);
+    /// assert_eq!(pc.time_intervals(), &[TimeInterval::new_unchecked(0, 10), TimeInterval::new_unchecked(5, 15)]);
     ///
-    /// let reduced_collection = collection.remove_column("foo").unwrap();
+    /// if let FeatureDataRef::NullableNumber(values) = pc.data("value").unwrap() {
+    ///     assert_eq!(values.nulls(), vec![false, true]);
+    /// } else {
+    ///     unreachable!();
+    /// }
     ///
-    /// assert_eq!(reduced_collection.len(), 2);
-    /// assert!(reduced_collection.data("foo").is_err());
+    /// let invalid = "x,y\n0.0,0.1\nfoo,1.1\n";
     ///
-    /// assert!(reduced_collection.remove_column("foo").is_err());
+    /// assert_eq!(
+    ///     MultiPointCollection::from_csv(invalid.as_bytes(), &CsvColumns::new("x", "y"))
+    ///         .unwrap_err()
+    ///         .to_string(),
+    ///     "CSV parse error in line 3, column 1: Coordinate is not a number"
+    /// );
     /// ```
-    fn remove_column(&self, column: &str) -> Result<Self> {
-        ensure!(
-            !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
-            error::FeatureCollection {
-                details: "Must not remove a non-existing or mandatory column"
-            }
-        );
+    pub fn from_csv<R: Read>(reader: R, columns: &CsvColumns) -> Result<Self> {
+        let points = csv_io::read_points(reader, columns)?;
 
-        let mut columns = vec![
-            Field::new(
-                Self::FEATURE_COLUMN_NAME,
-                Self::multi_points_data_type(),
-                false,
-            ),
-            Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
-        ];
-        let mut column_values: Vec<ArrayRef> = vec![
-            self.data
-                .column_by_name(Self::FEATURE_COLUMN_NAME)
-                .unwrap()
-                .clone(),
-            self.data
-                .column_by_name(Self::TIME_COLUMN_NAME)
-                .unwrap()
-                .clone(),
-        ];
+        let mut builder = Self::builder();
 
-        for (column_name, column_type) in &self.types {
-            if column_name == column {
-                continue;
+        for (column, data_type) in &points.columns {
+            builder.add_column(column, *data_type)?;
+        }
+
+        for (row, &coordinate) in points.coordinates.iter().enumerate() {
+            builder.append_coordinate(coordinate.into())?;
+            builder.append_time_interval(points.time_intervals[row])?;
+
+            for (column_index, (column, _)) in points.columns.iter().enumerate() {
+                builder.append_data(column, points.value(row, column_index)?)?;
             }
 
-            columns.push(Field::new(
-                &column_name,
-                column_type.arrow_data_type(),
-                column_type.nullable(),
-            ));
-            column_values.push(self.data.column_by_name(&column_name).unwrap().clone());
+            builder.finish_row()?;
         }
 
-        let mut types = self.types.clone();
-        types.remove(column);
-
-        Ok(Self {
-            data: struct_array_from_data(columns, column_values, self.data.len()),
-            types,
-        })
+        builder.build()
     }
-}
 
-impl Filterable for MultiPointCollection {
+    /// Writes the point collection as CSV with a header row into `writer`.
+    ///
+    /// Every feature must consist of a single point. The time intervals are only written
+    /// if the time columns are set. Null values become empty cells.
+    ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
-    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData};
-    /// use geoengine_datatypes::operations::Filterable;
+    /// use geoengine_datatypes::collections::{CsvColumns, MultiPointCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData};
     /// use std::collections::HashMap;
     ///
     /// let pc = MultiPointCollection::from_data(
-    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
-    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
-    ///     HashMap::new(),
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.5).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::NullableDecimal(vec![Some(1), None]));
+    ///         map
+    ///     },
     /// ).unwrap();
     ///
-    /// assert_eq!(pc.len(), 3);
-    ///
-    /// let filtered = pc.filter(vec![false, true, false]).unwrap();
+    /// let mut buffer = Vec::new();
+    /// pc.write_csv(&mut buffer, &CsvColumns::new("x", "y").with_time(Some("start"), Some("end"))).unwrap();
     ///
-    /// assert_eq!(filtered.len(), 1);
+    /// assert_eq!(
+    ///     String::from_utf8(buffer).unwrap(),
+    ///     "x,y,start,end,foo\n0.0,0.0,0,1,1\n1.0,1.5,1,2,\n"
+    /// );
     /// ```
-    fn filter(&self, mask: Vec<bool>) -> Result<Self> {
+    pub fn write_csv<W: Write>(&self, writer: W, columns: &CsvColumns) -> Result<()> {
         ensure!(
-            mask.len() == self.data.len(),
-            error::MaskLengthDoesNotMatchCollectionLength {
-                mask_length: mask.len(),
-                collection_length: self.data.len(),
+            self.is_simple(),
+            error::Csv {
+                details: "Only collections of single points can be written as CSV"
             }
         );
 
-        let filter_array: BooleanArray = mask.into();
-
-        // TODO: use filter directly on struct array when it is implemented
-
-        let filtered_data: Vec<(Field, ArrayRef)> =
-            if let DataType::Struct(columns) = self.data.data().data_type() {
-                let mut filtered_data: Vec<(Field, ArrayRef)> = Vec::with_capacity(columns.len());
-                for (column, array) in columns.iter().zip(self.data.columns()) {
-                    match column.name().as_str() {
-                        Self::FEATURE_COLUMN_NAME => filtered_data.push((
-                            column.clone(),
-                            Arc::new(coordinates_filter(downcast_array(array), &filter_array)?),
-                        )),
-                        Self::TIME_COLUMN_NAME => filtered_data.push((
-                            column.clone(),
-                            Arc::new(time_interval_filter(downcast_array(array), &filter_array)?),
-                        )),
-                        _ => filtered_data
-                            .push((column.clone(), filter(array.as_ref(), &filter_array)?)),
-                    }
-                }
-                filtered_data
-            } else {
-                unreachable!("data column must be a struct")
-            };
-
-        Ok(Self {
-            data: filtered_data.into(),
-            types: self.types.clone(),
-        })
-    }
-}
-
-fn coordinates_filter(features: &ListArray, filter_array: &BooleanArray) -> Result<ListArray> {
-    let mut new_features = ListBuilder::new(FixedSizeListBuilder::new(Float64Builder::new(2), 2));
-
-    for feature_index in 0..features.len() {
-        if filter_array.value(feature_index) {
-            let coordinate_builder = new_features.values();
-
-            let old_coordinates = features.value(feature_index);
-
-            for coordinate_index in 0..features.value_length(feature_index) {
-                let old_floats_array = downcast_array::<FixedSizeListArray>(&old_coordinates)
-                    .value(coordinate_index as usize);
-
-                let old_floats: &Float64Array = downcast_array(&old_floats_array);
-
-                let float_builder = coordinate_builder.values();
-                float_builder.append_slice(old_floats.value_slice(0, 2))?;
-
-                coordinate_builder.append(true)?;
-            }
-
-            new_features.append(true)?;
-        }
+        let mut column_names: Vec<&str> = self.types.keys().map(String::as_str).collect();
+        column_names.sort_unstable();
+
+        let data_columns = column_names
+            .into_iter()
+            .map(|column| Ok((column, csv_io::csv_values(&self.data(column)?, self.len())?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let coordinates: Vec<(f64, f64)> = self
+            .coordinates()
+            .iter()
+            .map(|coordinate| (coordinate.x, coordinate.y))
+            .collect();
+
+        csv_io::write_points(
+            writer,
+            columns,
+            &coordinates,
+            self.time_intervals(),
+            &data_columns,
+        )
     }
 
-    Ok(new_features.finish())
-}
-
-fn time_interval_filter(
-    time_intervals: &FixedSizeListArray,
-    filter_array: &BooleanArray,
-) -> Result<FixedSizeListArray> {
-    let mut new_time_intervals = FixedSizeListBuilder::new(Date64Builder::new(2), 2);
-
-    for feature_index in 0..time_intervals.len() {
-        if !filter_array.value(feature_index) {
-            continue;
-        }
-
-        let old_timestamps_ref = time_intervals.value(feature_index);
-        let old_timestamps: &Date64Array = downcast_array(&old_timestamps_ref);
-
-        let date_builder = new_time_intervals.values();
-        date_builder.append_slice(old_timestamps.value_slice(0, 2))?;
+    /// Serializes the point collection as CSV.
+    ///
+    /// See `write_csv` for details on the layout.
+    pub fn to_csv(&self, columns: &CsvColumns) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.write_csv(&mut buffer, columns)?;
 
-        new_time_intervals.append(true)?;
+        // the CSV writer only writes the given UTF-8 strings
+        Ok(String::from_utf8(buffer).expect("CSV must be valid UTF-8"))
     }
 
-    Ok(new_time_intervals.finish())
-}
-
-/// A row-by-row builder for a point collection
-pub struct MultiPointCollectionBuilder {
-    coordinates_builder: ListBuilder<FixedSizeListBuilder<Float64Builder>>,
-    time_intervals_builder: FixedSizeListBuilder<Date64Builder>,
-    builders: HashMap<String, Box<dyn ArrayBuilder>>,
-    types: HashMap<String, FeatureDataType>,
-    rows: usize,
-}
-
-impl Default for MultiPointCollectionBuilder {
-    /// Creates a builder for a point collection
+    /// Writes the geometry of each feature as WKT.
+    ///
+    /// Features with a single coordinate become `POINT`s, all others `MULTIPOINT`s.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
     ///
-    /// let builder = MultiPointCollectionBuilder::default();
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.5).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::default(), TimeInterval::default()],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.to_wkt(), vec!["POINT (0 0)", "MULTIPOINT ((1 1.5), (2 2))"]);
     /// ```
     ///
-    fn default() -> Self {
-        Self {
-            coordinates_builder: ListBuilder::new(FixedSizeListBuilder::new(
-                Float64Builder::new(0),
-                2,
-            )),
-            time_intervals_builder: FixedSizeListBuilder::new(Date64Builder::new(0), 2),
-            builders: Default::default(),
-            types: Default::default(),
-            rows: 0,
-        }
+    pub fn to_wkt(&self) -> Vec<String> {
+        self.feature_coordinates()
+            .map(well_known::multi_point_to_wkt)
+            .collect()
     }
-}
 
-impl MultiPointCollectionBuilder {
-    /// Adds a column to the collection.
-    /// Must happen before data insertions.
+    /// Writes the geometry of each feature as little endian WKB.
+    ///
+    /// Features with a single coordinate become `Point`s, all others `MultiPoint`s.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
-    /// use geoengine_datatypes::primitives::FeatureDataType;
+    /// use geoengine_datatypes::collections::{MultiPointCollection, MultiPointCollectionBuilder};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.5).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::default(), TimeInterval::default()],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let wkb = pc.to_wkb();
+    ///
+    /// assert_eq!(wkb[0].len(), 21);
+    /// assert_eq!(wkb[1].len(), 51);
     ///
     /// let mut builder = MultiPointCollectionBuilder::default();
+    /// for feature in &wkb {
+    ///     builder.append_wkb(feature).unwrap();
+    ///     builder.append_time_interval(TimeInterval::default()).unwrap();
+    ///     builder.finish_row().unwrap();
+    /// }
     ///
-    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
-    /// builder.add_column("__features", FeatureDataType::Number).unwrap_err();
+    /// assert_eq!(builder.build().unwrap().coordinates(), pc.coordinates());
     /// ```
     ///
-    pub fn add_column(&mut self, name: &str, data_type: FeatureDataType) -> Result<()> {
-        ensure!(
-            self.rows == 0,
-            error::FeatureCollectionBuilderException {
-                details: "It is not allowed to add further columns after data was inserted",
-            }
-        );
-        ensure!(
-            !MultiPointCollection::is_reserved_name(name) && !self.types.contains_key(name),
-            error::ColumnNameConflict {
-                name: name.to_string()
-            }
-        );
+    pub fn to_wkb(&self) -> Vec<Vec<u8>> {
+        self.feature_coordinates()
+            .map(well_known::multi_point_to_wkb)
+            .collect()
+    }
 
-        self.builders
-            .insert(name.into(), data_type.arrow_builder(0));
-        self.types.insert(name.into(), data_type);
+    /// Iterates over the coordinates of each feature
+    fn feature_coordinates(&self) -> impl Iterator<Item = &[Coordinate2D]> {
+        let coordinates = self.coordinates();
 
-        Ok(())
+        self.multi_point_offsets()
+            .windows(2)
+            .map(move |window| &coordinates[window[0] as usize..window[1] as usize])
     }
 
-    /// Finishes a row and checks for completion
+    /// Creates a new collection that contains the features of `self` followed by those of `other`.
+    ///
+    /// Both collections must have the same columns with the same data types.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
-    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, TimeInterval};
     ///
-    /// let mut builder = MultiPointCollectionBuilder::default();
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     [("foo".to_string(), FeatureData::Decimal(vec![1]))].iter().cloned().collect(),
+    /// ).unwrap();
+    /// let other = MultiPointCollection::from_data(
+    ///     vec![vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(1, 2)],
+    ///     [("foo".to_string(), FeatureData::Decimal(vec![2]))].iter().cloned().collect(),
+    /// ).unwrap();
     ///
-    /// builder.append_coordinate((0.0, 0.0).into());
-    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
+    /// let appended = pc.append(&other).unwrap();
     ///
-    /// builder.finish_row().unwrap();
+    /// assert_eq!(appended.len(), 2);
+    /// assert_eq!(appended.multi_point_offsets(), &[0, 1, 3]);
+    /// if let FeatureDataRef::Decimal(decimals) = appended.data("foo").unwrap() {
+    ///     assert_eq!(decimals.as_ref(), &[1, 2]);
+    /// } else {
+    ///     unreachable!();
+    /// }
     ///
-    /// builder.append_coordinate((0.0, 0.0).into());
+    /// let different = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     [("foo".to_string(), FeatureData::Number(vec![1.]))].iter().cloned().collect(),
+    /// ).unwrap();
     ///
-    /// builder.finish_row().unwrap_err();
+    /// assert!(pc.append(&different).unwrap_err().to_string().contains("foo (Decimal vs. Number)"));
     /// ```
     ///
-    pub fn finish_row(&mut self) -> Result<()> {
-        let rows = self.rows + 1;
-
-        ensure!(
-            self.coordinates_builder.len() == rows
-                && self.time_intervals_builder.len() == rows
-                && self.builders.values().all(|builder| builder.len() == rows),
-            error::FeatureCollectionBuilderException {
-                details: "Cannot finish row when child data is missing",
-            }
-        );
-
-        self.rows = rows;
-
-        Ok(())
+    pub fn append(&self, other: &Self) -> Result<Self> {
+        Self::concatenated(&[self, other])
     }
 
-    /// Adds a coordinate to the builder
+    /// Concatenates the features of all `collections` in order.
+    ///
+    /// All collections must have the same columns with the same data types,
+    /// otherwise the error lists the differing columns.
+    /// Concatenating no collections results in an empty collection.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
-    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
     ///
-    /// let mut builder = MultiPointCollectionBuilder::default();
+    /// let tiles = (0..3)
+    ///     .map(|i| {
+    ///         MultiPointCollection::from_data(
+    ///             vec![vec![(f64::from(i), 0.).into()]],
+    ///             vec![TimeInterval::new_unchecked(0, 1)],
+    ///             HashMap::new(),
+    ///         )
+    ///         .unwrap()
+    ///     })
+    ///     .collect::<Vec<_>>();
     ///
-    /// builder.append_coordinate((0.0, 0.0).into()).unwrap();
-    /// builder.append_coordinate((1.0, 1.0).into()).unwrap_err();
+    /// let merged = MultiPointCollection::concat(&tiles).unwrap();
+    ///
+    /// assert_eq!(merged.len(), 3);
+    /// assert_eq!(merged.coordinates(), &[(0., 0.).into(), (1., 0.).into(), (2., 0.).into()]);
+    ///
+    /// assert!(MultiPointCollection::concat(&[]).unwrap().is_empty());
     /// ```
     ///
-    pub fn append_coordinate(&mut self, coordinate: Coordinate2D) -> Result<()> {
-        ensure!(
-            self.coordinates_builder.len() <= self.rows,
-            error::FeatureCollectionBuilderException {
-                details: "Cannot add another coordinate until row is finished",
-            }
-        );
+    pub fn concat(collections: &[Self]) -> Result<Self> {
+        Self::concatenated(&collections.iter().collect::<Vec<_>>())
+    }
 
-        Self::append_single_coordinate_to_builder(self.coordinates_builder.values(), coordinate)?;
+    fn concatenated(collections: &[&Self]) -> Result<Self> {
+        let first = match collections.first() {
+            Some(first) => *first,
+            None => return Ok(Self::empty()),
+        };
 
-        self.coordinates_builder.append(true)?;
+        for collection in &collections[1..] {
+            let differences = helpers::schema_differences(&first.types, &collection.types);
+            ensure!(
+                differences.is_empty(),
+                error::FeatureCollection {
+                    details: format!(
+                        "Cannot concatenate collections with different columns: {}",
+                        differences.join(", ")
+                    ),
+                }
+            );
+        }
 
-        Ok(())
+        if let [single] = collections {
+            return Ok((*single).clone());
+        }
+
+        let data: Vec<&StructArray> = collections
+            .iter()
+            .map(|collection| &collection.data)
+            .collect();
+
+        Ok(Self {
+            data: helpers::concat_struct_arrays(&data),
+            types: first.types.clone(),
+        })
     }
 
-    /// Adds a multi coordinate to the builder
+    /// Keeps the features that have a coordinate inside `bbox`, or only those with all coordinates
+    /// inside `bbox` if `all_coordinates` is set. Features without coordinates are never kept.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
-    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{BoundingBox2D, TimeInterval};
+    /// use std::collections::HashMap;
     ///
-    /// let mut builder = MultiPointCollectionBuilder::default();
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (5., 5.).into()], vec![(5., 5.).into()]],
+    ///     vec![TimeInterval::default(); 3],
+    ///     HashMap::new(),
+    /// ).unwrap();
     ///
-    /// builder.append_multi_coordinate(vec![(0.0, 0.1).into(), (1.0, 1.1).into()]).unwrap();
-    /// builder.append_multi_coordinate(vec![(2.0, 2.1).into()]).unwrap_err();
+    /// let bbox = BoundingBox2D::new((0., 0.).into(), (2., 2.).into()).unwrap();
+    ///
+    /// let any_inside = pc.filter_bbox(&bbox, false).unwrap();
+    /// assert_eq!(any_inside.multi_point_offsets(), &[0, 1, 3]);
+    ///
+    /// let all_inside = pc.filter_bbox(&bbox, true).unwrap();
+    /// assert_eq!(all_inside.coordinates(), &[(0., 0.).into()]);
+    ///
+    /// let none_inside = pc.filter_bbox(&BoundingBox2D::new((8., 8.).into(), (9., 9.).into()).unwrap(), false).unwrap();
+    /// assert!(none_inside.is_empty());
     /// ```
     ///
-    pub fn append_multi_coordinate(&mut self, coordinates: Vec<Coordinate2D>) -> Result<()> {
-        ensure!(
-            self.coordinates_builder.len() <= self.rows,
-            error::FeatureCollectionBuilderException {
-                details: "Cannot add another coordinate until row is finished",
-            }
-        );
-
-        let coordinate_builder = self.coordinates_builder.values();
-        for coordinate in coordinates {
-            Self::append_single_coordinate_to_builder(coordinate_builder, coordinate)?;
-        }
-
-        self.coordinates_builder.append(true)?;
-
-        Ok(())
-    }
-
-    fn append_single_coordinate_to_builder(
-        coordinate_builder: &mut FixedSizeListBuilder<Float64Builder>,
-        coordinate: Coordinate2D,
-    ) -> Result<()> {
-        let float_builder = coordinate_builder.values();
-        float_builder.append_value(coordinate.x)?;
-        float_builder.append_value(coordinate.y)?;
-
-        coordinate_builder.append(true)?;
+    pub fn filter_bbox(&self, bbox: &BoundingBox2D, all_coordinates: bool) -> Result<Self> {
+        let mask = self
+            .feature_coordinates()
+            .map(|coordinates| {
+                let mut inside = coordinates
+                    .iter()
+                    .map(|coordinate| bbox.contains_coordinate(coordinate));
+                if all_coordinates {
+                    !coordinates.is_empty() && inside.all(|is_inside| is_inside)
+                } else {
+                    inside.any(|is_inside| is_inside)
+                }
+            })
+            .collect();
 
-        Ok(())
+        self.filter(mask)
     }
 
-    /// Adds a time interval to the builder
+    /// Sorts the features by attribute columns or time.
+    ///
+    /// Later sort options break ties of earlier ones and features with equal keys keep their order.
+    /// NaNs are placed after all other numbers in both directions.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection, SortKey, SortOptions};
     /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use std::collections::HashMap;
     ///
-    /// let mut builder = MultiPointCollectionBuilder::default();
-    ///
-    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
-    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap_err();
-    /// ```
-    ///
-    pub fn append_time_interval(&mut self, time_interval: TimeInterval) -> Result<()> {
-        ensure!(
-            self.time_intervals_builder.len() <= self.rows,
-            error::FeatureCollectionBuilderException {
-                details: "Cannot add another time interval until row is finished",
-            }
-        );
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()], vec![(3., 3.).into()]],
+    ///     vec![
+    ///         TimeInterval::new_unchecked(3, 4),
+    ///         TimeInterval::new_unchecked(2, 3),
+    ///         TimeInterval::new_unchecked(1, 2),
+    ///         TimeInterval::new_unchecked(0, 1),
+    ///     ],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::NullableNumber(vec![Some(1.), None, Some(2.), Some(1.)]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let sorted = pc.sort_by(&[
+    ///     SortOptions::descending(SortKey::Column("foo".into())).nulls_first(),
+    ///     SortOptions::ascending(SortKey::TimeStart),
+    /// ]).unwrap();
+    ///
+    /// assert_eq!(
+    ///     sorted.coordinates(),
+    ///     &[(1., 1.).into(), (2., 2.).into(), (3., 3.).into(), (0., 0.).into()]
+    /// );
+    ///
+    /// let sorted = pc.sort_by(&[SortOptions::ascending(SortKey::TimeEnd)]).unwrap();
+    ///
+    /// assert_eq!(sorted.time_intervals()[0], TimeInterval::new_unchecked(0, 1));
+    /// ```
+    ///
+    pub fn sort_by(&self, sort_options: &[SortOptions]) -> Result<Self> {
+        self.take(&sort::sorted_indices(self, sort_options)?)
+    }
 
-        let date_builder = self.time_intervals_builder.values();
-        date_builder.append_value(time_interval.start())?;
-        date_builder.append_value(time_interval.end())?;
+    /// Joins the attributes of `other` to the features of this collection by the key columns `on`.
+    ///
+    /// Key columns must be text, decimal, categorical or bool columns of the same type in both
+    /// collections and null keys never match. A feature is repeated for each matching row of
+    /// `other`. For `JoinType::Left`, features without a match are kept and the columns of `other`
+    /// become nullable. Columns of `other` whose names are already in use get `suffix` appended.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{DataCollection, FeatureCollection, JoinType, MultiPointCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, FeatureDataType, NullableDataRef, TimeInterval};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::default(); 3],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("id".into(), FeatureData::Decimal(vec![1, 2, 3]));
+    ///         map.insert("name".into(), FeatureData::Text(vec!["a".into(), "b".into(), "c".into()]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let dc = DataCollection::from_data(
+    ///     vec![TimeInterval::default(); 2],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("id".into(), FeatureData::Decimal(vec![3, 1]));
+    ///         map.insert("name".into(), FeatureData::Number(vec![3., 1.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let inner = pc.join(&dc, &["id"], JoinType::Inner, "_right").unwrap();
+    ///
+    /// assert_eq!(inner.coordinates(), &[(0., 0.).into(), (2., 2.).into()]);
+    /// if let FeatureDataRef::Number(numbers) = inner.data("name_right").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[1., 3.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// let left = pc.join(&dc, &["id"], JoinType::Left, "_right").unwrap();
+    ///
+    /// assert_eq!(left.len(), 3);
+    /// assert_eq!(left.column_types()["name_right"], FeatureDataType::NullableNumber);
+    /// if let FeatureDataRef::NullableNumber(numbers) = left.data("name_right").unwrap() {
+    ///     assert_eq!(numbers.nulls(), vec![false, true, false]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    pub fn join<C: FeatureCollection>(
+        &self,
+        other: &C,
+        on: &[&str],
+        join_type: JoinType,
+        suffix: &str,
+    ) -> Result<Self> {
+        let (left_indices, right_indices): (Vec<usize>, Vec<Option<usize>>) =
+            join::join_indices(self, other, on, join_type)?
+                .into_iter()
+                .unzip();
 
-        self.time_intervals_builder.append(true)?;
+        let mut joined = self.take(&left_indices)?;
 
-        Ok(())
+        for (column, data) in join::joined_columns(other, on, &right_indices, join_type)? {
+            let name = if Self::is_reserved_name(&column) || self.types.contains_key(&column) {
+                format!("{}{}", column, suffix)
+            } else {
+                column
+            };
+
+            ensure!(
+                !Self::is_reserved_name(&name) && !joined.types.contains_key(&name),
+                error::ColumnNameConflict { name }
+            );
+
+            joined = joined.add_column(&name, data)?;
+        }
+
+        Ok(joined)
     }
 
-    /// Adds a data item to the current row
+    /// Groups the features by the key columns `keys` and aggregates numeric columns per group.
+    ///
+    /// The result has one feature per group, ordered by the first feature of each group. Its
+    /// coordinates are all coordinates of the group, its time interval is merged by `time_merge`
+    /// and its columns are the key columns followed by the aggregates. Null keys form groups of
+    /// their own and null values are ignored by the aggregates.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
-    /// use geoengine_datatypes::primitives::{FeatureDataValue, FeatureDataType, TimeInterval};
+    /// use geoengine_datatypes::collections::{Aggregate, AggregateFunction, MultiPointCollection, FeatureCollection, TimeMerge};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, TimeInterval};
+    /// use std::collections::HashMap;
     ///
-    /// let mut builder = MultiPointCollectionBuilder::default();
-    /// builder.add_column("foobar", FeatureDataType::Number);
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![
+    ///         TimeInterval::new_unchecked(0, 1),
+    ///         TimeInterval::new_unchecked(5, 6),
+    ///         TimeInterval::new_unchecked(2, 3),
+    ///     ],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("class".into(), FeatureData::Text(vec!["a".into(), "b".into(), "a".into()]));
+    ///         map.insert("value".into(), FeatureData::Number(vec![1., 2., 4.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
     ///
-    /// builder.append_data("foobar", FeatureDataValue::Number(0.)).unwrap();
-    /// builder.append_data("foobar", FeatureDataValue::Number(1.)).unwrap_err();
-    /// ```
+    /// let grouped = pc.group_by(
+    ///     &["class"],
+    ///     &[
+    ///         Aggregate::new(AggregateFunction::Count, "value", "count"),
+    ///         Aggregate::new(AggregateFunction::Mean, "value", "mean"),
+    ///     ],
+    ///     TimeMerge::Hull,
+    /// ).unwrap();
     ///
-    pub fn append_data(&mut self, column: &str, data: FeatureDataValue) -> Result<()> {
-        ensure!(
-            self.types.contains_key(column),
-            error::FeatureCollectionBuilderException {
-                details: format!("Column {} does not exist", column),
-            }
-        );
-
-        let data_builder = self.builders.get_mut(column).unwrap(); // previously checked
-
-        ensure!(
-            data_builder.len() <= self.rows,
-            error::FeatureCollectionBuilderException {
-                details: "Cannot add another data item until row is finished",
-            }
-        );
+    /// assert_eq!(grouped.len(), 2);
+    /// assert_eq!(grouped.coordinates(), &[(0., 0.).into(), (2., 2.).into(), (1., 1.).into()]);
+    /// assert_eq!(grouped.multi_point_offsets(), &[0, 2, 3]);
+    /// assert_eq!(grouped.time_intervals(), &[TimeInterval::new_unchecked(0, 3), TimeInterval::new_unchecked(5, 6)]);
+    /// if let FeatureDataRef::Number(means) = grouped.data("mean").unwrap() {
+    ///     assert_eq!(means.as_ref(), &[2.5, 2.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(pc.group_by(&["class"], &[], TimeMerge::Union).is_err());
+    /// ```
+    pub fn group_by(
+        &self,
+        keys: &[&str],
+        aggregates: &[Aggregate],
+        time_merge: TimeMerge,
+    ) -> Result<Self> {
+        let groups = group_by::group_indices(self, keys)?;
 
-        let _data_type = self.types.get(column).unwrap(); // previously checked
+        let mut columns = HashMap::with_capacity(keys.len() + aggregates.len());
 
-        ensure!(
-            mem::discriminant(&FeatureDataType::from(&data)) == mem::discriminant(_data_type), // same enum variant
-            error::FeatureCollectionBuilderException {
-                details: "Data type is wrong for the column",
-            }
-        );
+        for &key in keys {
+            columns.insert(
+                key.to_string(),
+                group_by::reduce(self, &groups, key, ColumnReduction::First)?,
+            );
+        }
 
-        match data {
-            FeatureDataValue::Number(value) => {
-                let number_builder: &mut Float64Builder = downcast_mut_array(data_builder.as_mut());
-                number_builder.append_value(value)?;
-            }
-            FeatureDataValue::NullableNumber(value) => {
-                let number_builder: &mut Float64Builder = downcast_mut_array(data_builder.as_mut());
-                number_builder.append_option(value)?;
-            }
-            FeatureDataValue::Text(value) => {
-                let string_builder: &mut StringBuilder = downcast_mut_array(data_builder.as_mut());
-                string_builder.append_value(&value)?;
-            }
-            FeatureDataValue::NullableText(value) => {
-                let string_builder: &mut StringBuilder = downcast_mut_array(data_builder.as_mut());
-                if let Some(v) = &value {
-                    string_builder.append_value(&v)?;
-                } else {
-                    string_builder.append_null()?;
+        for aggregate in aggregates {
+            ensure!(
+                !Self::is_reserved_name(&aggregate.name) && !columns.contains_key(&aggregate.name),
+                error::ColumnNameConflict {
+                    name: aggregate.name.clone()
                 }
-            }
-            FeatureDataValue::Decimal(value) => {
-                let decimal_builder: &mut Int64Builder = downcast_mut_array(data_builder.as_mut());
-                decimal_builder.append_value(value)?;
-            }
-            FeatureDataValue::NullableDecimal(value) => {
-                let decimal_builder: &mut Int64Builder = downcast_mut_array(data_builder.as_mut());
-                decimal_builder.append_option(value)?;
-            }
-            FeatureDataValue::Categorical(value) => {
-                let categorical_builder: &mut UInt8Builder =
-                    downcast_mut_array(data_builder.as_mut());
-                categorical_builder.append_value(value)?;
-            }
-            FeatureDataValue::NullableCategorical(value) => {
-                let categorical_builder: &mut UInt8Builder =
-                    downcast_mut_array(data_builder.as_mut());
-                categorical_builder.append_option(value)?;
-            }
+            );
+
+            columns.insert(
+                aggregate.name.clone(),
+                group_by::aggregate(self, &groups, aggregate)?,
+            );
         }
 
-        Ok(())
+        self.merged_groups(&groups, columns, time_merge)
     }
 
-    /// Builds the point collection
+    /// Combines the features with equal values in the key column `key` into a multi-point per key.
+    ///
+    /// The time intervals of a group are merged by `time_merge`. Every other column is reduced by
+    /// its entry in `reductions` or else by `ColumnReduction::Unique`. Null keys form groups of their own.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use geoengine_datatypes::collections::{MultiPointCollectionBuilder, FeatureCollection};
-    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataType, FeatureDataValue};
-    ///
-    /// let mut builder = MultiPointCollectionBuilder::default();
-    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
-    ///
-    /// builder.append_coordinate((0.0, 0.1).into()).unwrap();
-    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
-    /// builder.append_data("foobar", FeatureDataValue::Number(0.));
+    /// use geoengine_datatypes::collections::{ColumnReduction, MultiPointCollection, FeatureCollection, TimeMerge};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, TimeInterval};
+    /// use std::collections::HashMap;
     ///
-    /// builder.finish_row().unwrap();
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("cluster".into(), FeatureData::Decimal(vec![7, 8, 7]));
+    ///         map.insert("name".into(), FeatureData::Text(vec!["a".into(), "b".into(), "a".into()]));
+    ///         map.insert("value".into(), FeatureData::Number(vec![1., 2., 3.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
     ///
-    /// builder.append_coordinate((1.0, 1.1).into()).unwrap();
-    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap();
-    /// builder.append_data("foobar", FeatureDataValue::Number(1.));
+    /// let mut reductions = HashMap::new();
+    /// reductions.insert("value".to_string(), ColumnReduction::First);
     ///
-    /// builder.finish_row().unwrap();
+    /// let collected = pc.collect_by("cluster", &reductions, TimeMerge::Union).unwrap();
     ///
-    /// let point_collection = builder.build().unwrap();
+    /// assert_eq!(collected.multi_point_offsets(), &[0, 2, 3]);
+    /// assert_eq!(collected.coordinates(), &[(0., 0.).into(), (2., 2.).into(), (1., 1.).into()]);
+    /// if let FeatureDataRef::Number(values) = collected.data("value").unwrap() {
+    ///     assert_eq!(values.as_ref(), &[1., 2.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
     ///
-    /// assert_eq!(point_collection.len(), 2);
-    /// assert_eq!(point_collection.coordinates(), &[(0.0, 0.1).into(), (1.0, 1.1).into()]);
+    /// // the values of `value` differ within a cluster
+    /// assert!(pc.collect_by("cluster", &HashMap::new(), TimeMerge::Union).is_err());
     /// ```
-    ///
-    pub fn build(mut self) -> Result<MultiPointCollection> {
-        ensure!(
-            self.coordinates_builder.len() == self.rows
-                && self.time_intervals_builder.len() == self.rows
-                && self
-                    .builders
-                    .values()
-                    .all(|builder| builder.len() == self.rows),
-            error::FeatureCollectionBuilderException {
-                details: "Cannot build a point collection out of unfinished rows",
-            }
-        );
-
-        let mut columns = Vec::with_capacity(self.types.len() + 2);
-        let mut builders: Vec<Box<dyn ArrayBuilder>> = Vec::with_capacity(self.types.len() + 2);
-
-        columns.push(Field::new(
-            MultiPointCollection::FEATURE_COLUMN_NAME,
-            MultiPointCollection::multi_points_data_type(),
-            false,
-        ));
-        builders.push(Box::new(self.coordinates_builder));
+    pub fn collect_by(
+        &self,
+        key: &str,
+        reductions: &HashMap<String, ColumnReduction>,
+        time_merge: TimeMerge,
+    ) -> Result<Self> {
+        let groups = group_by::group_indices(self, &[key])?;
 
-        columns.push(Field::new(
-            MultiPointCollection::TIME_COLUMN_NAME,
-            MultiPointCollection::time_data_type(),
-            false,
-        ));
-        builders.push(Box::new(self.time_intervals_builder));
+        let mut columns = HashMap::with_capacity(self.types.len());
+        for column in self.types.keys() {
+            let reduction = if column == key {
+                ColumnReduction::First
+            } else {
+                reductions
+                    .get(column)
+                    .copied()
+                    .unwrap_or(ColumnReduction::Unique)
+            };
 
-        for (column_name, builder) in self.builders.drain() {
-            let column_type = self.types.get(&column_name).unwrap(); // column must exist
-            columns.push(Field::new(
-                &column_name,
-                column_type.arrow_data_type(),
-                column_type.nullable(),
-            ));
-            builders.push(builder);
+            columns.insert(
+                column.clone(),
+                group_by::reduce(self, &groups, column, reduction)?,
+            );
         }
 
-        let mut struct_builder = StructBuilder::new(columns, builders);
+        self.merged_groups(&groups, columns, time_merge)
+    }
 
-        for _ in 0..self.rows {
-            struct_builder.append(true)?;
+    /// Creates a collection with a feature for each of the `groups` of features and the given columns
+    fn merged_groups(
+        &self,
+        groups: &[Vec<usize>],
+        columns: HashMap<String, FeatureData>,
+        time_merge: TimeMerge,
+    ) -> Result<Self> {
+        let feature_coordinates: Vec<&[Coordinate2D]> = self.feature_coordinates().collect();
+        let coordinates = groups
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .flat_map(|&row| feature_coordinates[row].iter().copied())
+                    .collect()
+            })
+            .collect();
+
+        let time_intervals =
+            group_by::merge_time_intervals(self.time_intervals(), groups, time_merge)?;
+
+        Self::from_data(coordinates, time_intervals, columns)
+    }
+
+    /// Creates a simple collection with a feature for each coordinate.
+    ///
+    /// The time interval and attribute values of a feature are repeated for each of its
+    /// coordinates and features without coordinates are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, TimeInterval};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into(), (1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::Decimal(vec![1, 2]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert!(!pc.is_simple());
+    ///
+    /// let exploded = pc.explode().unwrap();
+    ///
+    /// assert!(exploded.is_simple());
+    /// assert_eq!(exploded.coordinates(), pc.coordinates());
+    /// assert_eq!(
+    ///     exploded.time_intervals(),
+    ///     &[TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)]
+    /// );
+    /// if let FeatureDataRef::Decimal(values) = exploded.data("foo").unwrap() {
+    ///     assert_eq!(values.as_ref(), &[1, 1, 2]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    pub fn explode(&self) -> Result<Self> {
+        let rows: Vec<Option<usize>> = self
+            .feature_coordinates()
+            .enumerate()
+            .flat_map(|(row, coordinates)| vec![Some(row); coordinates.len()])
+            .collect();
+
+        let coordinates = self
+            .feature_coordinates()
+            .flatten()
+            .map(|&coordinate| vec![coordinate])
+            .collect();
+
+        let time_intervals = self.time_intervals();
+        let time_intervals = rows
+            .iter()
+            .flatten()
+            .map(|&row| time_intervals[row])
+            .collect();
+
+        let mut data = HashMap::with_capacity(self.types.len());
+        for column in self.types.keys() {
+            let column_data = helpers::feature_data_from_ref(&self.data(column)?, self.len())?;
+            data.insert(
+                column.clone(),
+                join::gather_feature_data(column_data, &rows, false),
+            );
         }
 
-        Ok(MultiPointCollection {
-            data: struct_builder.finish(),
-            types: self.types,
-        })
+        Self::from_data(coordinates, time_intervals, data)
     }
-}
 
-fn struct_array_from_data(
-    columns: Vec<Field>,
-    column_values: Vec<ArrayRef>,
-    number_of_features: usize,
-) -> StructArray {
-    StructArray::from(
-        ArrayData::builder(DataType::Struct(columns))
-            .child_data(column_values.into_iter().map(|a| a.data()).collect())
-            .len(number_of_features)
-            .build(),
-    )
-}
+    /// Splits the collection into consecutive chunks that respect `limit`.
+    ///
+    /// The chunks are slices of this collection and together contain all of its features. The
+    /// bytes of a feature are the bytes of its coordinates, time interval and attribute values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{ChunkLimit, MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()], vec![(3., 3.).into()]],
+    ///     vec![TimeInterval::default(); 3],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let chunks: Vec<MultiPointCollection> = pc.chunks(ChunkLimit::Features(2)).unwrap().collect();
+    ///
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks[0].len(), 2);
+    /// assert_eq!(chunks[1].coordinates(), &[(3., 3.).into()]);
+    ///
+    /// // a feature with a single coordinate has 16 bytes of coordinates and 16 bytes of time
+    /// let chunks: Vec<MultiPointCollection> = pc.chunks(ChunkLimit::Bytes(40)).unwrap().collect();
+    ///
+    /// assert_eq!(chunks.iter().map(FeatureCollection::len).collect::<Vec<_>>(), vec![1, 1, 1]);
+    /// ```
+    pub fn chunks(&self, limit: ChunkLimit) -> Result<impl Iterator<Item = Self> + '_> {
+        let bounds = chunks::chunk_bounds(self.len(), limit, || {
+            let mut sizes = chunks::feature_value_byte_sizes(self)?;
+            for (size, coordinates) in sizes.iter_mut().zip(self.feature_coordinates()) {
+                *size += mem::size_of_val(coordinates);
+            }
+            Ok(sizes)
+        })?;
+
+        Ok(bounds.into_iter().map(move |(offset, len)| {
+            self.slice(offset, len)
+                .expect("chunk bounds must lie within the collection")
+        }))
+    }
+
+    /// Serialize the point collection into the Arrow IPC stream or file format
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{ArrowIpcFormat, MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef, NullableDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("numbers".into(), FeatureData::NullableNumber(vec![Some(0.), None]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// for format in &[ArrowIpcFormat::Stream, ArrowIpcFormat::File] {
+    ///     let bytes = pc.to_arrow_ipc(*format).unwrap();
+    ///     let deserialized = MultiPointCollection::from_arrow_ipc(&bytes, *format).unwrap();
+    ///
+    ///     assert_eq!(deserialized.len(), 2);
+    ///     assert_eq!(deserialized.coordinates(), pc.coordinates());
+    ///     assert_eq!(deserialized.time_intervals(), pc.time_intervals());
+    ///     if let FeatureDataRef::NullableNumber(numbers) = deserialized.data("numbers").unwrap() {
+    ///         assert_eq!(numbers.nulls(), vec![false, true]);
+    ///     } else {
+    ///         unreachable!();
+    ///     }
+    /// }
+    /// ```
+    pub fn to_arrow_ipc(&self, format: ArrowIpcFormat) -> Result<Vec<u8>> {
+        ipc::struct_array_to_ipc(&self.data, format)
+    }
+
+    /// Deserialize a point collection from the Arrow IPC stream or file format.
+    ///
+    /// The feature and time columns must have the layout of a point collection and
+    /// all other columns must be valid feature data columns.
+    pub fn from_arrow_ipc(bytes: &[u8], format: ArrowIpcFormat) -> Result<Self> {
+        let data = ipc::struct_array_from_ipc(bytes, format)?;
+
+        let fields = if let DataType::Struct(fields) = data.data().data_type() {
+            fields.clone()
+        } else {
+            unreachable!("data column must be a struct")
+        };
+
+        let mut types = HashMap::with_capacity(fields.len());
+
+        for field in &fields {
+            let expected_data_type = match field.name().as_str() {
+                Self::FEATURE_COLUMN_NAME => Self::multi_points_data_type(),
+                Self::TIME_COLUMN_NAME => Self::time_data_type(),
+                _ => {
+                    types.insert(
+                        field.name().clone(),
+                        FeatureDataType::from_arrow_data_type(
+                            field.data_type(),
+                            field.is_nullable(),
+                        )?,
+                    );
+                    continue;
+                }
+            };
+
+            ensure!(
+                field.data_type() == &expected_data_type,
+                error::FeatureCollection {
+                    details: format!(
+                        "Column {} has type {:?}, expected {:?}",
+                        field.name(),
+                        field.data_type(),
+                        expected_data_type
+                    )
+                }
+            );
+        }
+
+        ensure!(
+            data.column_by_name(Self::FEATURE_COLUMN_NAME).is_some()
+                && data.column_by_name(Self::TIME_COLUMN_NAME).is_some(),
+            error::FeatureCollection {
+                details: "Point collections must contain a feature and a time column"
+            }
+        );
+
+        Ok(Self { data, types })
+    }
+
+    /// Compares the collections, where coordinates and numbers are compared with `float_eq`
+    fn eq_with<F>(&self, other: &Self, float_eq: F) -> bool
+    where
+        F: Fn(f64, f64) -> bool + Copy,
+    {
+        self.len() == other.len()
+            && self.features().zip(other.features()).all(|(a, b)| {
+                let (a, b) = (a.coordinates(), b.coordinates());
+                a.len() == b.len()
+                    && a.iter()
+                        .zip(b)
+                        .all(|(a, b)| float_eq(a.x, b.x) && float_eq(a.y, b.y))
+            })
+            && helpers::feature_data_eq(self, other, float_eq)
+    }
+}
+
+impl FeatureCollection for MultiPointCollection {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn is_simple(&self) -> bool {
+        self.len() == self.coordinates().len()
+    }
+
+    /// Retrieves a data column of this point collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData, FeatureDataRef, NullableDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("numbers".into(), FeatureData::Number(vec![0., 1., 2.]));
+    ///         map.insert("number_nulls".into(), FeatureData::NullableNumber(vec![Some(0.), None, Some(2.)]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.len(), 3);
+    ///
+    /// if let FeatureDataRef::Number(numbers) = pc.data("numbers").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[0., 1., 2.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// if let FeatureDataRef::NullableNumber(numbers) = pc.data("number_nulls").unwrap() {
+    ///     assert_eq!(numbers.as_ref()[0], 0.);
+    ///     assert_eq!(numbers.as_ref()[2], 2.);
+    ///     assert_eq!(numbers.nulls(), vec![false, true, false]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    ///
+    fn data(&self, column_name: &str) -> Result<FeatureDataRef> {
+        ensure!(
+            !Self::is_reserved_name(column_name),
+            error::FeatureCollection {
+                details: "Cannot access reserved columns via `data()` method"
+            }
+        );
+
+        helpers::feature_data_ref(&self.data, &self.types, column_name)
+    }
+
+    /// Retrieves the time intervals of this point collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.len(), 3);
+    ///
+    /// let time_intervals = pc.time_intervals();
+    ///
+    /// assert_eq!(time_intervals.len(), 3);
+    /// assert_eq!(
+    ///     time_intervals,
+    ///     &[TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)]
+    /// );
+    /// ```
+    ///
+    fn time_intervals(&self) -> &[TimeInterval] {
+        helpers::time_intervals_slice(&self.data, Self::TIME_COLUMN_NAME)
+    }
+
+    fn column_types(&self) -> &HashMap<String, FeatureDataType> {
+        &self.types
+    }
+
+    fn count_byte_size(&self, counter: &mut ByteSizeCounter) -> usize {
+        counter.count_struct_array(&self.data)
+    }
+
+    /// Extend the collection by an additional column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataType, TimeInterval, FeatureDataValue, FeatureDataRef};
+    ///
+    /// let collection = {
+    ///     let mut builder = MultiPointCollection::builder();
+    ///     builder.add_column("foo", FeatureDataType::Number);
+    ///
+    ///     builder.append_coordinate((0., 0.).into());
+    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
+    ///     builder.append_data("foo", FeatureDataValue::Number(0.));
+    ///     builder.finish_row();
+    ///
+    ///     builder.append_coordinate((1., 1.).into());
+    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
+    ///     builder.append_data("foo", FeatureDataValue::Number(1.));
+    ///     builder.finish_row();
+    ///
+    ///     builder.build().unwrap()
+    /// };
+    ///
+    /// assert_eq!(collection.len(), 2);
+    ///
+    /// let extended_collection = collection.add_column("bar", FeatureData::Number(vec![2., 4.])).unwrap();
+    ///
+    /// assert_eq!(extended_collection.len(), 2);
+    /// if let FeatureDataRef::Number(numbers) = extended_collection.data("foo").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[0., 1.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// if let FeatureDataRef::Number(numbers) = extended_collection.data("bar").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[2., 4.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(new_column) && self.data.column_by_name(new_column).is_none(),
+            error::FeatureCollection {
+                details: "Cannot extend collection with name that is reserved or already in use"
+            }
+        );
+
+        ensure!(
+            data.len() == self.data.len(),
+            error::FeatureCollection {
+                details: "Length of new feature data column must match length of collection"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.insert(new_column.to_string(), FeatureDataType::from(&data));
+
+        Ok(Self {
+            data: helpers::struct_array_with_column(&self.data, new_column, &data)?,
+            types,
+        })
+    }
+
+    /// Removes a column and returns an updated collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataType, TimeInterval, FeatureDataValue, FeatureDataRef};
+    ///
+    /// let collection = {
+    ///     let mut builder = MultiPointCollection::builder();
+    ///     builder.add_column("foo", FeatureDataType::Number);
+    ///
+    ///     builder.append_coordinate((0., 0.).into());
+    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
+    ///     builder.append_data("foo", FeatureDataValue::Number(0.));
+    ///     builder.finish_row();
+    ///
+    ///     builder.append_coordinate((1., 1.).into());
+    ///     builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
+    ///     builder.append_data("foo", FeatureDataValue::Number(1.));
+    ///     builder.finish_row();
+    ///
+    ///     builder.build().unwrap()
+    /// };
+    ///
+    /// assert_eq!(collection.len(), 2);
+    /// assert!(collection.data("foo").is_ok());
+    ///
+    /// let reduced_collection = collection.remove_column("foo").unwrap();
+    ///
+    /// assert_eq!(reduced_collection.len(), 2);
+    /// assert!(reduced_collection.data("foo").is_err());
+    ///
+    /// assert!(reduced_collection.remove_column("foo").is_err());
+    /// ```
+    fn remove_column(&self, column: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+            error::FeatureCollection {
+                details: "Must not remove a non-existing or mandatory column"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.remove(column);
+
+        Ok(Self {
+            data: helpers::struct_array_without_column(&self.data, column),
+            types,
+        })
+    }
+
+    /// Renames a column and returns an updated collection that shares all columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, TimeInterval};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()]],
+    ///     vec![TimeInterval::default()],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::Number(vec![0.]));
+    ///         map.insert("bar".into(), FeatureData::Decimal(vec![1]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let renamed = pc.rename_column("foo", "baz").unwrap();
+    ///
+    /// assert!(renamed.data("foo").is_err());
+    /// if let FeatureDataRef::Number(numbers) = renamed.data("baz").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[0.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(pc.rename_column("foo", "bar").is_err());
+    /// assert!(pc.rename_column("foo", "__time").is_err());
+    /// assert!(pc.rename_column("__time", "time").is_err());
+    /// ```
+    fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(old_name) && self.data.column_by_name(old_name).is_some(),
+            error::FeatureCollection {
+                details: "Must not rename a non-existing or mandatory column"
+            }
+        );
+        ensure!(
+            !Self::is_reserved_name(new_name) && self.data.column_by_name(new_name).is_none(),
+            error::ColumnNameConflict { name: new_name }
+        );
+
+        let mut types = self.types.clone();
+        if let Some(data_type) = types.remove(old_name) {
+            types.insert(new_name.to_string(), data_type);
+        }
+
+        Ok(Self {
+            data: helpers::struct_array_with_renamed_column(&self.data, old_name, new_name),
+            types,
+        })
+    }
+
+    /// Keeps only the given data columns and returns an updated collection that shares these columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()]],
+    ///     vec![TimeInterval::default()],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::Number(vec![0.]));
+    ///         map.insert("bar".into(), FeatureData::Decimal(vec![1]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let selected = pc.select_columns(&["bar"]).unwrap();
+    ///
+    /// assert!(selected.data("foo").is_err());
+    /// assert!(selected.data("bar").is_ok());
+    /// assert_eq!(selected.coordinates(), pc.coordinates());
+    /// assert_eq!(selected.time_intervals(), pc.time_intervals());
+    ///
+    /// assert!(pc.select_columns(&["baz"]).is_err());
+    /// assert!(pc.select_columns(&["__features"]).is_err());
+    /// ```
+    fn select_columns(&self, columns: &[&str]) -> Result<Self> {
+        for &column in columns {
+            ensure!(
+                !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+                error::FeatureCollection {
+                    details: format!(
+                        "Cannot select the non-existing or mandatory column {}",
+                        column
+                    )
+                }
+            );
+        }
+
+        let types = self
+            .types
+            .iter()
+            .filter(|(column, _)| columns.contains(&column.as_str()))
+            .map(|(column, data_type)| (column.clone(), *data_type))
+            .collect();
+
+        Ok(Self {
+            data: helpers::struct_array_with_columns(&self.data, |column| {
+                Self::is_reserved_name(column) || columns.contains(&column)
+            }),
+            types,
+        })
+    }
+}
+
+impl Filterable for MultiPointCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData};
+    /// use geoengine_datatypes::operations::Filterable;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into()], vec![(2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.len(), 3);
+    ///
+    /// let filtered = pc.filter(vec![false, true, false]).unwrap();
+    ///
+    /// assert_eq!(filtered.len(), 1);
+    /// ```
+    fn filter(&self, mask: Vec<bool>) -> Result<Self> {
+        ensure!(
+            mask.len() == self.data.len(),
+            error::MaskLengthDoesNotMatchCollectionLength {
+                mask_length: mask.len(),
+                collection_length: self.data.len(),
+            }
+        );
+
+        let filter_array: BooleanArray = mask.into();
+
+        Ok(Self {
+            data: helpers::filter_struct_array(
+                &self.data,
+                &filter_array,
+                Self::FEATURE_COLUMN_NAME,
+                |features, filter_array| {
+                    Ok(Arc::new(coordinates_filter(
+                        downcast_array(features),
+                        filter_array,
+                    )?))
+                },
+                Self::TIME_COLUMN_NAME,
+            )?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+impl Takeable for MultiPointCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, NullableDataRef, TimeInterval};
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()], vec![(3., 3.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::NullableDecimal(vec![Some(0), None, Some(2)]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let taken = pc.take(&[2, 1, 2]).unwrap();
+    ///
+    /// assert_eq!(taken.len(), 3);
+    /// assert_eq!(
+    ///     taken.coordinates(),
+    ///     &[(3., 3.).into(), (1., 1.).into(), (2., 2.).into(), (3., 3.).into()]
+    /// );
+    /// assert_eq!(taken.multi_point_offsets(), &[0, 1, 3, 4]);
+    /// assert_eq!(
+    ///     taken.time_intervals(),
+    ///     &[TimeInterval::new_unchecked(2, 3), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)]
+    /// );
+    /// if let FeatureDataRef::NullableDecimal(decimals) = taken.data("foo").unwrap() {
+    ///     assert_eq!(decimals.as_ref(), &[2, 0, 2]);
+    ///     assert_eq!(decimals.nulls(), vec![false, true, false]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(pc.take(&[3]).is_err());
+    /// ```
+    fn take(&self, indices: &[usize]) -> Result<Self> {
+        Ok(Self {
+            data: helpers::take_struct_array(&self.data, indices)?,
+            types: self.types.clone(),
+        })
+    }
+
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, FeatureDataRef, TimeInterval};
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()], vec![(3., 3.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2), TimeInterval::new_unchecked(2, 3)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::Text(vec!["a".into(), "b".into(), "c".into()]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let page = pc.slice(1, 2).unwrap();
+    ///
+    /// assert_eq!(page.len(), 2);
+    /// assert_eq!(page.coordinates(), &pc.coordinates()[1..]);
+    /// assert_eq!(page.multi_point_offsets(), &[0, 2, 3]);
+    /// assert_eq!(page.time_intervals(), &pc.time_intervals()[1..]);
+    /// if let FeatureDataRef::Text(texts) = page.data("foo").unwrap() {
+    ///     assert_eq!(texts.text_at(0).unwrap(), "b");
+    ///     assert_eq!(texts.text_at(1).unwrap(), "c");
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// // the coordinates are not copied
+    /// assert_eq!(page.coordinates().as_ptr(), pc.coordinates()[1..].as_ptr());
+    ///
+    /// assert!(pc.slice(2, 2).is_err());
+    /// ```
+    fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        Ok(Self {
+            data: helpers::slice_struct_array(&self.data, offset, len)?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+impl PartialEq for MultiPointCollection {
+    /// Two collections are equal if their features have the same coordinates, time intervals and
+    /// values, regardless of whether their memory is shared
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::NullableDecimal(vec![Some(1), None]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc, pc.clone());
+    /// assert_eq!(pc.slice(1, 1).unwrap(), pc.take(&[1]).unwrap());
+    /// assert_ne!(pc.slice(0, 1).unwrap(), pc.take(&[1]).unwrap());
+    /// ```
+    #[allow(clippy::float_cmp)]
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_with(other, |a, b| a == b)
+    }
+}
+
+impl ApproxEq for &MultiPointCollection {
+    type Margin = F64Margin;
+
+    /// Compares the collections like `==` but allows coordinates and numbers to differ within `margin`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use float_cmp::ApproxEq;
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    /// use std::collections::HashMap;
+    ///
+    /// let collection = |x: f64, number: f64| {
+    ///     MultiPointCollection::from_data(
+    ///         vec![vec![(x, 0.).into()]],
+    ///         vec![TimeInterval::new_unchecked(0, 1)],
+    ///         {
+    ///             let mut map = HashMap::new();
+    ///             map.insert("foo".into(), FeatureData::Number(vec![number]));
+    ///             map
+    ///         },
+    ///     ).unwrap()
+    /// };
+    ///
+    /// assert_ne!(collection(0.1 + 0.2, 1.), collection(0.3, 1.));
+    /// assert!(collection(0.1 + 0.2, 1.).approx_eq(&collection(0.3, 1.), (0., 2)));
+    /// assert!(collection(0.3, 0.1 + 0.2).approx_eq(&collection(0.3, 0.3), (0., 2)));
+    /// assert!(collection(0.3, 1.).approx_ne(&collection(0.3, 1.1), (0.01, 2)));
+    /// ```
+    fn approx_eq<M: Into<Self::Margin>>(self, other: Self, margin: M) -> bool {
+        let margin = margin.into();
+        self.eq_with(other, |a, b| a.approx_eq(b, margin))
+    }
+}
+
+/// A view on a single feature of a `MultiPointCollection`
+#[derive(Clone, Copy, Debug)]
+pub struct MultiPointFeature<'c> {
+    collection: &'c MultiPointCollection,
+    index: usize,
+}
+
+impl<'c> MultiPointFeature<'c> {
+    /// The position of the feature in its collection
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The coordinates of the feature
+    pub fn coordinates(&self) -> &'c [Coordinate2D] {
+        let offsets = self.collection.multi_point_offsets();
+        &self.collection.coordinates()
+            [offsets[self.index] as usize..offsets[self.index + 1] as usize]
+    }
+
+    /// The time interval of the feature
+    pub fn time_interval(&self) -> TimeInterval {
+        self.collection.time_intervals()[self.index]
+    }
+
+    /// The value of the feature in a data column
+    pub fn data(&self, column: &str) -> Result<FeatureDataValue> {
+        self.collection.data(column)?.get(self.index)
+    }
+}
+
+/// The serialized layout of a `MultiPointCollection`
+#[derive(Deserialize, Serialize)]
+struct SerializableMultiPointCollection {
+    coordinates: Vec<Vec<Coordinate2D>>,
+    time_intervals: Vec<TimeInterval>,
+    data: BTreeMap<String, FeatureData>,
+}
+
+impl Serialize for MultiPointCollection {
+    /// Serializes the point collection with the following layout:
+    ///
+    /// - `coordinates`: a list of features, each a list of `{"x": …, "y": …}` coordinates
+    /// - `time_intervals`: a list of `{"start": …, "end": …}` time intervals in ms
+    /// - `data`: a map from column names to the column data, tagged by its `FeatureDataType`, e.g.,
+    ///   `{"NullableNumber": [1.0, null]}`, where `null` denotes a null value
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollection;
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPointCollection::from_data(
+    ///     vec![vec![(0., 0.).into()], vec![(1., 1.).into(), (2., 2.).into()]],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("foo".into(), FeatureData::NullableDecimal(vec![Some(1), None]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     serde_json::to_value(&pc).unwrap(),
+    ///     serde_json::json!({
+    ///         "coordinates": [[{"x": 0.0, "y": 0.0}], [{"x": 1.0, "y": 1.0}, {"x": 2.0, "y": 2.0}]],
+    ///         "time_intervals": [{"start": 0, "end": 1}, {"start": 1, "end": 2}],
+    ///         "data": {"foo": {"NullableDecimal": [1, null]}},
+    ///     })
+    /// );
+    /// ```
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let coordinates = self.coordinates();
+
+        let serializable = SerializableMultiPointCollection {
+            coordinates: self
+                .multi_point_offsets()
+                .windows(2)
+                .map(|window| coordinates[window[0] as usize..window[1] as usize].to_vec())
+                .collect(),
+            time_intervals: self.time_intervals().to_vec(),
+            data: self
+                .types
+                .keys()
+                .map(|column| {
+                    let data = helpers::feature_data_from_ref(&self.data(column)?, self.len())?;
+                    Ok((column.clone(), data))
+                })
+                .collect::<Result<_>>()
+                .map_err(S::Error::custom)?,
+        };
+
+        serializable.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MultiPointCollection {
+    /// Deserializes a point collection from the layout described in its `Serialize` implementation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataRef, NullableDataRef};
+    ///
+    /// let pc: MultiPointCollection = serde_json::from_str(r#"{
+    ///     "coordinates": [[{"x": 0.0, "y": 0.0}], [{"x": 1.0, "y": 1.0}]],
+    ///     "time_intervals": [{"start": 0, "end": 1}, {"start": 1, "end": 2}],
+    ///     "data": {"foo": {"NullableNumber": [null, 2.0]}}
+    /// }"#).unwrap();
+    ///
+    /// assert_eq!(pc.len(), 2);
+    /// if let FeatureDataRef::NullableNumber(numbers) = pc.data("foo").unwrap() {
+    ///     assert_eq!(numbers.nulls(), vec![true, false]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let serializable = SerializableMultiPointCollection::deserialize(deserializer)?;
+
+        let len = serializable.coordinates.len();
+        if serializable.time_intervals.len() != len
+            || serializable.data.values().any(|data| data.len() != len)
+        {
+            return Err(D::Error::custom(
+                "coordinates, time intervals and data must have the same length",
+            ));
+        }
+
+        Self::from_data(
+            serializable.coordinates,
+            serializable.time_intervals,
+            serializable.data.into_iter().collect(),
+        )
+        .map_err(D::Error::custom)
+    }
+}
+
+fn coordinates_filter(features: &ListArray, filter_array: &BooleanArray) -> Result<ListArray> {
+    let coordinates_ref = features.values();
+    let coordinates: &FixedSizeListArray = downcast_array(&coordinates_ref);
+
+    let floats_ref = coordinates.values();
+    let floats: &Float64Array = downcast_array(&floats_ref);
+
+    let mut new_features = ListBuilder::new(FixedSizeListBuilder::new(Float64Builder::new(2), 2));
+
+    for feature_index in 0..features.len() {
+        if filter_array.value(feature_index) {
+            let coordinate_builder = new_features.values();
+
+            let first_coordinate = features.value_offset(feature_index) as usize;
+            let last_coordinate = features.value_offset(feature_index + 1) as usize;
+
+            for coordinate_index in first_coordinate..last_coordinate {
+                let float_builder = coordinate_builder.values();
+                float_builder.append_slice(floats.value_slice(2 * coordinate_index, 2))?;
+
+                coordinate_builder.append(true)?;
+            }
+
+            new_features.append(true)?;
+        }
+    }
+
+    Ok(new_features.finish())
+}
+
+/// A row-by-row builder for a point collection
+pub struct MultiPointCollectionBuilder {
+    coordinates_builder: ListBuilder<FixedSizeListBuilder<Float64Builder>>,
+    time_intervals_builder: FixedSizeListBuilder<Date64Builder>,
+    builders: HashMap<String, Box<dyn ArrayBuilder>>,
+    types: HashMap<String, FeatureDataType>,
+    rows: usize,
+}
+
+impl Default for MultiPointCollectionBuilder {
+    /// Creates a builder for a point collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    ///
+    /// let builder = MultiPointCollectionBuilder::default();
+    /// ```
+    ///
+    fn default() -> Self {
+        Self {
+            coordinates_builder: ListBuilder::new(FixedSizeListBuilder::new(
+                Float64Builder::new(0),
+                2,
+            )),
+            time_intervals_builder: FixedSizeListBuilder::new(Date64Builder::new(0), 2),
+            builders: Default::default(),
+            types: Default::default(),
+            rows: 0,
+        }
+    }
+}
+
+impl MultiPointCollectionBuilder {
+    /// Adds a column to the collection.
+    /// Must happen before data insertions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::FeatureDataType;
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    /// builder.add_column("__features", FeatureDataType::Number).unwrap_err();
+    /// ```
+    ///
+    pub fn add_column(&mut self, name: &str, data_type: FeatureDataType) -> Result<()> {
+        ensure!(
+            self.rows == 0,
+            error::FeatureCollectionBuilderException {
+                details: "It is not allowed to add further columns after data was inserted",
+            }
+        );
+        ensure!(
+            !MultiPointCollection::is_reserved_name(name) && !self.types.contains_key(name),
+            error::ColumnNameConflict {
+                name: name.to_string()
+            }
+        );
+
+        self.builders
+            .insert(name.into(), data_type.arrow_builder(0));
+        self.types.insert(name.into(), data_type);
+
+        Ok(())
+    }
+
+    /// Finishes a row and checks for completion
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// builder.append_coordinate((0.0, 0.0).into());
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1));
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_coordinate((0.0, 0.0).into());
+    ///
+    /// builder.finish_row().unwrap_err();
+    /// ```
+    ///
+    pub fn finish_row(&mut self) -> Result<()> {
+        let rows = self.rows + 1;
+
+        ensure!(
+            self.coordinates_builder.len() == rows
+                && self.time_intervals_builder.len() == rows
+                && self.builders.values().all(|builder| builder.len() == rows),
+            error::FeatureCollectionBuilderException {
+                details: "Cannot finish row when child data is missing",
+            }
+        );
+
+        self.rows = rows;
+
+        Ok(())
+    }
+
+    /// Adds a coordinate to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// builder.append_coordinate((0.0, 0.0).into()).unwrap();
+    /// builder.append_coordinate((1.0, 1.0).into()).unwrap_err();
+    /// ```
+    ///
+    pub fn append_coordinate(&mut self, coordinate: Coordinate2D) -> Result<()> {
+        ensure!(
+            self.coordinates_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another coordinate until row is finished",
+            }
+        );
+
+        Self::append_single_coordinate_to_builder(self.coordinates_builder.values(), coordinate)?;
+
+        self.coordinates_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a multi coordinate to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// builder.append_multi_coordinate(vec![(0.0, 0.1).into(), (1.0, 1.1).into()]).unwrap();
+    /// builder.append_multi_coordinate(vec![(2.0, 2.1).into()]).unwrap_err();
+    /// ```
+    ///
+    pub fn append_multi_coordinate(&mut self, coordinates: Vec<Coordinate2D>) -> Result<()> {
+        ensure!(
+            self.coordinates_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another coordinate until row is finished",
+            }
+        );
+
+        let coordinate_builder = self.coordinates_builder.values();
+        for coordinate in coordinates {
+            Self::append_single_coordinate_to_builder(coordinate_builder, coordinate)?;
+        }
+
+        self.coordinates_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds the coordinates of a WKT `POINT` or `MULTIPOINT` to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// builder.append_wkt("MULTIPOINT ((0 0.1), (1 1.1))").unwrap();
+    /// builder.append_time_interval(TimeInterval::default()).unwrap();
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_wkt("POINT (2 2.1)").unwrap();
+    /// builder.append_time_interval(TimeInterval::default()).unwrap();
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_wkt("LINESTRING (0 0, 1 1)").unwrap_err();
+    ///
+    /// let pc = builder.build().unwrap();
+    ///
+    /// assert_eq!(pc.coordinates(), &[(0.0, 0.1).into(), (1.0, 1.1).into(), (2.0, 2.1).into()]);
+    /// ```
+    ///
+    pub fn append_wkt(&mut self, wkt: &str) -> Result<()> {
+        self.append_multi_coordinate(well_known::multi_point_from_wkt(wkt)?)
+    }
+
+    /// Adds the coordinates of a WKB `Point` or `MultiPoint` to the builder.
+    ///
+    /// Both byte orders and extended WKB with an SRID are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// // big endian POINT (1 2)
+    /// let mut wkb = vec![0, 0, 0, 0, 1];
+    /// wkb.extend_from_slice(&1.0_f64.to_be_bytes());
+    /// wkb.extend_from_slice(&2.0_f64.to_be_bytes());
+    ///
+    /// builder.append_wkb(&wkb).unwrap();
+    /// builder.append_time_interval(TimeInterval::default()).unwrap();
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_wkb(&wkb[..10]).unwrap_err();
+    ///
+    /// let pc = builder.build().unwrap();
+    ///
+    /// assert_eq!(pc.coordinates(), &[(1.0, 2.0).into()]);
+    /// ```
+    ///
+    pub fn append_wkb(&mut self, wkb: &[u8]) -> Result<()> {
+        self.append_multi_coordinate(well_known::multi_point_from_wkb(wkb)?)
+    }
+
+    fn append_single_coordinate_to_builder(
+        coordinate_builder: &mut FixedSizeListBuilder<Float64Builder>,
+        coordinate: Coordinate2D,
+    ) -> Result<()> {
+        let float_builder = coordinate_builder.values();
+        float_builder.append_value(coordinate.x)?;
+        float_builder.append_value(coordinate.y)?;
+
+        coordinate_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a time interval to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval};
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    ///
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap_err();
+    /// ```
+    ///
+    pub fn append_time_interval(&mut self, time_interval: TimeInterval) -> Result<()> {
+        ensure!(
+            self.time_intervals_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another time interval until row is finished",
+            }
+        );
+
+        let date_builder = self.time_intervals_builder.values();
+        date_builder.append_value(time_interval.start())?;
+        date_builder.append_value(time_interval.end())?;
+
+        self.time_intervals_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a data item to the current row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPointCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureDataValue, FeatureDataType, TimeInterval};
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    /// builder.add_column("foobar", FeatureDataType::Number);
+    ///
+    /// builder.append_data("foobar", FeatureDataValue::Number(0.)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(1.)).unwrap_err();
+    /// ```
+    ///
+    pub fn append_data(&mut self, column: &str, data: FeatureDataValue) -> Result<()> {
+        ensure!(
+            self.types.contains_key(column),
+            error::FeatureCollectionBuilderException {
+                details: format!("Column {} does not exist", column),
+            }
+        );
+
+        let data_builder = self.builders.get_mut(column).unwrap(); // previously checked
+
+        ensure!(
+            data_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another data item until row is finished",
+            }
+        );
+
+        let _data_type = self.types.get(column).unwrap(); // previously checked
+
+        ensure!(
+            mem::discriminant(&FeatureDataType::from(&data)) == mem::discriminant(_data_type), // same enum variant
+            error::FeatureCollectionBuilderException {
+                details: "Data type is wrong for the column",
+            }
+        );
+
+        helpers::append_feature_data_value(data_builder.as_mut(), data)
+    }
+
+    /// Builds the point collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollectionBuilder, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataType, FeatureDataValue};
+    ///
+    /// let mut builder = MultiPointCollectionBuilder::default();
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    ///
+    /// builder.append_coordinate((0.0, 0.1).into()).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(0.));
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_coordinate((1.0, 1.1).into()).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(1.));
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// let point_collection = builder.build().unwrap();
+    ///
+    /// assert_eq!(point_collection.len(), 2);
+    /// assert_eq!(point_collection.coordinates(), &[(0.0, 0.1).into(), (1.0, 1.1).into()]);
+    /// ```
+    ///
+    pub fn build(mut self) -> Result<MultiPointCollection> {
+        ensure!(
+            self.coordinates_builder.len() == self.rows
+                && self.time_intervals_builder.len() == self.rows
+                && self
+                    .builders
+                    .values()
+                    .all(|builder| builder.len() == self.rows),
+            error::FeatureCollectionBuilderException {
+                details: "Cannot build a point collection out of unfinished rows",
+            }
+        );
+
+        let mut columns = Vec::with_capacity(self.types.len() + 2);
+        let mut builders: Vec<Box<dyn ArrayBuilder>> = Vec::with_capacity(self.types.len() + 2);
+
+        columns.push(Field::new(
+            MultiPointCollection::FEATURE_COLUMN_NAME,
+            MultiPointCollection::multi_points_data_type(),
+            false,
+        ));
+        builders.push(Box::new(self.coordinates_builder));
+
+        columns.push(Field::new(
+            MultiPointCollection::TIME_COLUMN_NAME,
+            MultiPointCollection::time_data_type(),
+            false,
+        ));
+        builders.push(Box::new(self.time_intervals_builder));
+
+        for (column_name, builder) in self.builders.drain() {
+            let column_type = self.types.get(&column_name).unwrap(); // column must exist
+            columns.push(Field::new(
+                &column_name,
+                column_type.arrow_data_type(),
+                column_type.nullable(),
+            ));
+            builders.push(builder);
+        }
+
+        if self.rows == 0 {
+            return Ok(MultiPointCollection {
+                data: helpers::empty_struct_array(columns),
+                types: self.types,
+            });
+        }
+
+        let mut struct_builder = StructBuilder::new(columns, builders);
+
+        for _ in 0..self.rows {
+            struct_builder.append(true)?;
+        }
+
+        Ok(MultiPointCollection {
+            data: struct_builder.finish(),
+            types: self.types,
+        })
+    }
+}
 
 #[cfg(test)]
 mod test {
     use super::*;
 
+    use crate::collections::{AggregateFunction, ChunkLimit, SortKey};
+    use crate::primitives::NullableDataRef;
+
+    #[test]
+    fn clone() {
+        let pc = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(0, 1),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+                map
+            },
+        )
+        .unwrap();
+
+        let cloned = pc.clone();
+
+        assert_eq!(pc.len(), cloned.len());
+        assert_eq!(pc.coordinates(), cloned.coordinates());
+    }
+
+    #[test]
+    fn arrow_ipc_round_trip() {
+        let pc = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into(), (2., 2.).into()],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert(
+                    "text".into(),
+                    FeatureData::Text(vec!["a".into(), "b".into()]),
+                );
+                map.insert(
+                    "nullable_decimal".into(),
+                    FeatureData::NullableDecimal(vec![None, Some(2)]),
+                );
+                map.insert(
+                    "categorical".into(),
+                    FeatureData::NullableCategorical(vec![Some(1), None]),
+                );
+                map
+            },
+        )
+        .unwrap();
+
+        for &format in &[ArrowIpcFormat::Stream, ArrowIpcFormat::File] {
+            let bytes = pc.to_arrow_ipc(format).unwrap();
+            let deserialized = MultiPointCollection::from_arrow_ipc(&bytes, format).unwrap();
+
+            assert_eq!(deserialized.types, pc.types);
+            assert_eq!(deserialized.coordinates(), pc.coordinates());
+            assert_eq!(deserialized.time_intervals(), pc.time_intervals());
+        }
+
+        assert!(MultiPointCollection::from_arrow_ipc(
+            &pc.to_arrow_ipc(ArrowIpcFormat::Stream).unwrap(),
+            ArrowIpcFormat::File
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn arrow_ipc_round_trip_empty() {
+        let empty_with_column = MultiPointCollection::from_data(vec![], vec![], {
+            let mut map = HashMap::new();
+            map.insert("number".into(), FeatureData::NullableNumber(vec![]));
+            map
+        })
+        .unwrap();
+
+        for collection in &[MultiPointCollection::empty(), empty_with_column] {
+            for &format in &[ArrowIpcFormat::Stream, ArrowIpcFormat::File] {
+                let bytes = collection.to_arrow_ipc(format).unwrap();
+                let deserialized = MultiPointCollection::from_arrow_ipc(&bytes, format).unwrap();
+
+                assert!(deserialized.is_empty());
+                assert_eq!(deserialized.types, collection.types);
+                assert!(deserialized.coordinates().is_empty());
+                assert!(deserialized.time_intervals().is_empty());
+            }
+        }
+    }
+
+    #[test]
+    fn arrow_ipc_rejects_wrong_feature_type() {
+        let data = StructArray::from(vec![
+            (
+                Field::new(
+                    MultiPointCollection::FEATURE_COLUMN_NAME,
+                    DataType::Float64,
+                    false,
+                ),
+                Arc::new(Float64Array::from(vec![0.])) as arrow::array::ArrayRef,
+            ),
+            (
+                Field::new(
+                    MultiPointCollection::TIME_COLUMN_NAME,
+                    MultiPointCollection::time_data_type(),
+                    false,
+                ),
+                Arc::new(
+                    helpers::time_intervals_builder(&[TimeInterval::new_unchecked(0, 1)])
+                        .unwrap()
+                        .finish(),
+                ),
+            ),
+        ]);
+
+        let bytes = ipc::struct_array_to_ipc(&data, ArrowIpcFormat::Stream).unwrap();
+
+        assert!(MultiPointCollection::from_arrow_ipc(&bytes, ArrowIpcFormat::Stream).is_err());
+    }
+
+    #[test]
+    fn geo_json_nulls() {
+        let pc = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into(), (2., 2.).into()],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert(
+                    "text".into(),
+                    FeatureData::NullableText(vec![None, Some("b".into())]),
+                );
+                map.insert(
+                    "decimal".into(),
+                    FeatureData::NullableDecimal(vec![Some(1), None]),
+                );
+                map.insert(
+                    "categorical".into(),
+                    FeatureData::NullableCategorical(vec![None, Some(2)]),
+                );
+                map
+            },
+        )
+        .unwrap();
+
+        let geo_json: serde_json::Value = serde_json::from_str(&pc.to_geo_json().unwrap()).unwrap();
+
+        assert_eq!(
+            geo_json["features"][0]["properties"],
+            json!({
+                "__time": {"start": 0, "end": 1},
+                "text": null,
+                "decimal": 1,
+                "categorical": null,
+            })
+        );
+        assert_eq!(
+            geo_json["features"][1]["properties"],
+            json!({
+                "__time": {"start": 1, "end": 2},
+                "text": "b",
+                "decimal": null,
+                "categorical": 2,
+            })
+        );
+        assert_eq!(geo_json["features"][1]["geometry"]["type"], "MultiPoint");
+    }
+
+    #[test]
+    fn geo_json_round_trip() {
+        let pc = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into(), (2., 2.).into()],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert(
+                    "text".into(),
+                    FeatureData::NullableText(vec![None, Some("b".into())]),
+                );
+                map.insert("number".into(), FeatureData::Number(vec![0.5, 1.5]));
+                map.insert("decimal".into(), FeatureData::Decimal(vec![1, 2]));
+                map
+            },
+        )
+        .unwrap();
+
+        let deserialized = MultiPointCollection::from_geo_json(
+            &pc.to_geo_json().unwrap(),
+            &GeoJsonTimeProperties::default(),
+        )
+        .unwrap();
+
+        assert_eq!(deserialized.types, pc.types);
+        assert_eq!(deserialized.coordinates(), pc.coordinates());
+        assert_eq!(deserialized.multi_point_offsets(), pc.multi_point_offsets());
+        assert_eq!(deserialized.time_intervals(), pc.time_intervals());
+    }
+
     #[test]
-    fn clone() {
+    fn geo_json_unsupported_geometry() {
+        let geo_json = r#"{
+            "type": "FeatureCollection",
+            "features": [{
+                "type": "Feature",
+                "geometry": {"type": "Polygon", "coordinates": [[[0, 0], [1, 0], [1, 1], [0, 0]]]},
+                "properties": null
+            }]
+        }"#;
+
+        assert_eq!(
+            MultiPointCollection::from_geo_json(geo_json, &GeoJsonTimeProperties::default())
+                .unwrap_err(),
+            error::Error::GeoJsonUnsupportedGeometry {
+                geometry_type: "Polygon".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn serde_round_trip() {
         let pc = MultiPointCollection::from_data(
-            vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into(), (2., 2.).into()],
+            ],
             vec![
                 TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert(
+                    "text".into(),
+                    FeatureData::Text(vec!["a".into(), "b".into()]),
+                );
+                map.insert(
+                    "nullable_text".into(),
+                    FeatureData::NullableText(vec![Some("a".into()), None]),
+                );
+                map.insert(
+                    "nullable_number".into(),
+                    FeatureData::NullableNumber(vec![None, Some(1.5)]),
+                );
+                map.insert("decimal".into(), FeatureData::Decimal(vec![1, 2]));
+                map.insert(
+                    "nullable_categorical".into(),
+                    FeatureData::NullableCategorical(vec![Some(1), None]),
+                );
+                map
+            },
+        )
+        .unwrap();
+
+        let serialized = serde_json::to_string(&pc).unwrap();
+        let deserialized: MultiPointCollection = serde_json::from_str(&serialized).unwrap();
+
+        assert_eq!(deserialized.types, pc.types);
+        assert_eq!(deserialized.coordinates(), pc.coordinates());
+        assert_eq!(deserialized.multi_point_offsets(), pc.multi_point_offsets());
+        assert_eq!(deserialized.time_intervals(), pc.time_intervals());
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+
+        let empty: MultiPointCollection =
+            serde_json::from_str(&serde_json::to_string(&MultiPointCollection::empty()).unwrap())
+                .unwrap();
+        assert!(empty.is_empty());
+
+        let empty_with_columns = pc.filter(vec![false; 2]).unwrap();
+        let serialized = serde_json::to_string(&empty_with_columns).unwrap();
+        let deserialized: MultiPointCollection = serde_json::from_str(&serialized).unwrap();
+        assert!(deserialized.is_empty());
+        assert_eq!(deserialized.types, pc.types);
+        assert_eq!(serde_json::to_string(&deserialized).unwrap(), serialized);
+
+        assert!(serde_json::from_str::<MultiPointCollection>(
+            r#"{"coordinates": [[{"x": 0.0, "y": 0.0}]], "time_intervals": [], "data": {}}"#
+        )
+        .is_err());
+        assert!(serde_json::from_str::<MultiPointCollection>(
+            r#"{"coordinates": [], "time_intervals": [], "data": {"foo": {"Number": [1.0]}}}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn csv_round_trip() {
+        let pc = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+            vec![
                 TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
             ],
             {
                 let mut map = HashMap::new();
-                map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+                map.insert(
+                    "text".into(),
+                    FeatureData::NullableText(vec![None, Some("b".into())]),
+                );
+                map.insert("number".into(), FeatureData::Number(vec![1., 1.5]));
+                map.insert(
+                    "decimal".into(),
+                    FeatureData::NullableDecimal(vec![Some(1), None]),
+                );
                 map
             },
         )
         .unwrap();
 
-        let cloned = pc.clone();
+        let columns = CsvColumns::new("x", "y").with_time(Some("start"), Some("end"));
 
-        assert_eq!(pc.len(), cloned.len());
-        assert_eq!(pc.coordinates(), cloned.coordinates());
+        let deserialized =
+            MultiPointCollection::from_csv(pc.to_csv(&columns).unwrap().as_bytes(), &columns)
+                .unwrap();
+
+        assert_eq!(deserialized.types, pc.types);
+        assert_eq!(deserialized.coordinates(), pc.coordinates());
+        assert_eq!(deserialized.time_intervals(), pc.time_intervals());
+
+        let header_only = pc.filter(vec![false; 2]).unwrap().to_csv(&columns).unwrap();
+        assert_eq!(header_only, "x,y,start,end,decimal,number,text\n");
+
+        let deserialized =
+            MultiPointCollection::from_csv(header_only.as_bytes(), &columns).unwrap();
+        assert!(deserialized.is_empty());
+        let mut column_names: Vec<&str> = deserialized.types.keys().map(String::as_str).collect();
+        column_names.sort_unstable();
+        assert_eq!(column_names, vec!["decimal", "number", "text"]);
+    }
+
+    #[test]
+    fn csv_errors() {
+        let columns = CsvColumns::new("x", "y").with_time(Some("start"), None);
+
+        assert_eq!(
+            MultiPointCollection::from_csv("x,y,start\n0,0,\n1,1,foo\n".as_bytes(), &columns)
+                .unwrap_err(),
+            error::Error::CsvParse {
+                row: 3,
+                column: 3,
+                details: "Time is not an integer".to_string(),
+            }
+        );
+
+        assert!(MultiPointCollection::from_csv("a,y\n0,0\n".as_bytes(), &columns).is_err());
+
+        let multi_point = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into(), (1., 1.).into()]],
+            vec![TimeInterval::new_unchecked(0, 1)],
+            HashMap::new(),
+        )
+        .unwrap();
+
+        assert!(multi_point.to_csv(&columns).is_err());
+    }
+
+    #[test]
+    fn well_known_formats() {
+        let mut builder = MultiPointCollectionBuilder::default();
+
+        for wkt in &[
+            "point(0 1)",
+            "MULTIPOINT (1 2, 3 4)",
+            " MULTIPOINT ( (5 6) , (-7 8.5) ) ",
+            "MULTIPOINT EMPTY",
+        ] {
+            builder.append_wkt(wkt).unwrap();
+            builder
+                .append_time_interval(TimeInterval::default())
+                .unwrap();
+            builder.finish_row().unwrap();
+        }
+
+        // big endian extended WKB `SRID=4326;MULTIPOINT (9 10)` with a little endian point
+        let mut wkb = vec![0];
+        wkb.extend_from_slice(&0x2000_0004_u32.to_be_bytes());
+        wkb.extend_from_slice(&4326_u32.to_be_bytes());
+        wkb.extend_from_slice(&1_u32.to_be_bytes());
+        wkb.extend_from_slice(&[1, 1, 0, 0, 0]);
+        wkb.extend_from_slice(&9.0_f64.to_le_bytes());
+        wkb.extend_from_slice(&10.0_f64.to_le_bytes());
+
+        builder.append_wkb(&wkb).unwrap();
+        builder
+            .append_time_interval(TimeInterval::default())
+            .unwrap();
+        builder.finish_row().unwrap();
+
+        let multi_point = builder.build().unwrap();
+
+        assert_eq!(
+            multi_point.to_wkt(),
+            vec![
+                "POINT (0 1)",
+                "MULTIPOINT ((1 2), (3 4))",
+                "MULTIPOINT ((5 6), (-7 8.5))",
+                "MULTIPOINT EMPTY",
+                "POINT (9 10)",
+            ]
+        );
+
+        for (wkb, wkt) in multi_point.to_wkb().iter().zip(multi_point.to_wkt()) {
+            assert_eq!(
+                well_known::multi_point_from_wkb(wkb).unwrap(),
+                well_known::multi_point_from_wkt(&wkt).unwrap()
+            );
+        }
+
+        assert!(MultiPointCollection::empty().to_wkt().is_empty());
+
+        assert!(well_known::multi_point_from_wkt("POINT Z (1 2 3)").is_err());
+        assert!(well_known::multi_point_from_wkt("POINT (1 2 3)").is_err());
+        assert!(well_known::multi_point_from_wkt("POINT (1 a)").is_err());
+        assert!(well_known::multi_point_from_wkb(&wkb[..wkb.len() - 1]).is_err());
+        assert!(well_known::multi_point_from_wkb(&[wkb.clone(), vec![0]].concat()).is_err());
+    }
+
+    #[test]
+    fn concat() {
+        let collection = |x: f64, text: Option<&str>| {
+            MultiPointCollection::from_data(
+                vec![vec![(x, x).into()]],
+                vec![TimeInterval::new_unchecked(0, 1)],
+                [(
+                    "text".to_string(),
+                    FeatureData::NullableText(vec![text.map(str::to_string)]),
+                )]
+                .iter()
+                .cloned()
+                .collect(),
+            )
+            .unwrap()
+        };
+
+        let merged = MultiPointCollection::concat(&[
+            collection(0., Some("a")),
+            collection(1., None),
+            collection(2., Some("c")),
+        ])
+        .unwrap();
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged.time_intervals().len(), 3);
+        if let FeatureData::NullableText(texts) =
+            helpers::feature_data_from_ref(&merged.data("text").unwrap(), merged.len()).unwrap()
+        {
+            assert_eq!(
+                texts,
+                vec![Some("a".to_string()), None, Some("c".to_string())]
+            );
+        } else {
+            unreachable!();
+        }
+
+        let nothing = merged.filter(vec![false; 3]).unwrap();
+        assert_eq!(merged.append(&nothing).unwrap().len(), 3);
+        assert_eq!(nothing.append(&nothing).unwrap().len(), 0);
+
+        let error = merged
+            .append(&MultiPointCollection::empty())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("text (NullableText vs. missing)"));
+    }
+
+    #[test]
+    fn concat_slices() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into(), (2., 2.).into()],
+                vec![(3., 3.).into()],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+                TimeInterval::new_unchecked(2, 3),
+            ],
+            [
+                (
+                    "number".to_string(),
+                    FeatureData::NullableNumber(vec![Some(0.), None, Some(2.)]),
+                ),
+                (
+                    "text".to_string(),
+                    FeatureData::Text(vec!["a".into(), "bc".into(), "d".into()]),
+                ),
+                (
+                    "flag".to_string(),
+                    FeatureData::NullableBool(vec![Some(true), Some(false), None]),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let merged = MultiPointCollection::concat(&[
+            multi_point.slice(1, 2).unwrap(),
+            multi_point.filter(vec![false; 3]).unwrap(),
+            multi_point.take(&[0]).unwrap(),
+            multi_point.slice(1, 2).unwrap().slice(1, 1).unwrap(),
+        ])
+        .unwrap();
+        let expected = multi_point.take(&[1, 2, 0, 2]).unwrap();
+
+        assert_eq!(merged.multi_point_offsets(), &[0, 2, 3, 4, 5]);
+        assert_eq!(
+            serde_json::to_value(&merged).unwrap(),
+            serde_json::to_value(&expected).unwrap()
+        );
+    }
+
+    #[test]
+    fn slice_and_take() {
+        let len = 20;
+        let multi_point = MultiPointCollection::from_data(
+            (0..len)
+                .map(|i| vec![(i as f64, 0.).into(); i % 3 + 1])
+                .collect(),
+            (0..len)
+                .map(|i| TimeInterval::new_unchecked(i as i64, i as i64 + 1))
+                .collect(),
+            [(
+                "text".to_string(),
+                FeatureData::NullableText(
+                    (0..len)
+                        .map(|i| {
+                            if i % 2 == 0 {
+                                Some(i.to_string())
+                            } else {
+                                None
+                            }
+                        })
+                        .collect(),
+                ),
+            )]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let sliced = multi_point.slice(5, 10).unwrap().slice(3, 6).unwrap();
+        let taken = multi_point.take(&(8..14).collect::<Vec<_>>()).unwrap();
+
+        for collection in &[sliced, taken] {
+            assert_eq!(collection.len(), 6);
+            assert_eq!(collection.to_wkt()[0], "MULTIPOINT ((8 0), (8 0), (8 0))");
+            assert_eq!(
+                collection.time_intervals()[0],
+                TimeInterval::new_unchecked(8, 9)
+            );
+            if let FeatureData::NullableText(texts) =
+                helpers::feature_data_from_ref(&collection.data("text").unwrap(), 6).unwrap()
+            {
+                assert_eq!(
+                    texts,
+                    vec![
+                        Some("8".to_string()),
+                        None,
+                        Some("10".to_string()),
+                        None,
+                        Some("12".to_string()),
+                        None
+                    ]
+                );
+            } else {
+                unreachable!();
+            }
+
+            let bytes = collection.to_arrow_ipc(ArrowIpcFormat::Stream).unwrap();
+            let deserialized =
+                MultiPointCollection::from_arrow_ipc(&bytes, ArrowIpcFormat::Stream).unwrap();
+            assert_eq!(deserialized.coordinates(), collection.coordinates());
+            assert_eq!(deserialized.time_intervals(), collection.time_intervals());
+        }
+
+        for collection in &[
+            multi_point.slice(len, 0).unwrap(),
+            multi_point.take(&[]).unwrap(),
+        ] {
+            assert!(collection.is_empty());
+            assert!(collection.coordinates().is_empty());
+            assert!(collection.time_intervals().is_empty());
+            assert!(collection.data("text").is_ok());
+        }
+
+        assert!(MultiPointCollection::empty()
+            .slice(0, 0)
+            .unwrap()
+            .is_empty());
+        assert!(MultiPointCollection::empty().take(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn slice_shares_attribute_buffers() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into()]; 10],
+            vec![TimeInterval::default(); 10],
+            [
+                (
+                    "number".to_string(),
+                    FeatureData::NullableNumber(
+                        (0..10)
+                            .map(|i| if i % 3 == 0 { None } else { Some(f64::from(i)) })
+                            .collect(),
+                    ),
+                ),
+                (
+                    "decimal".to_string(),
+                    FeatureData::Decimal((0..10).collect()),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let sliced = multi_point.slice(2, 7).unwrap().slice(1, 4).unwrap();
+
+        let sliced_numbers = sliced.data("number").unwrap();
+        assert!(matches!(
+            sliced_numbers.get(0).unwrap(),
+            FeatureDataValue::NullableNumber(None)
+        ));
+        assert!(matches!(
+            sliced_numbers.get(1).unwrap(),
+            FeatureDataValue::NullableNumber(Some(x)) if x == 4.
+        ));
+
+        if let (
+            FeatureDataRef::NullableNumber(numbers),
+            FeatureDataRef::NullableNumber(sliced_numbers),
+        ) = (multi_point.data("number").unwrap(), sliced_numbers)
+        {
+            assert_eq!(&sliced_numbers.as_ref()[1..3], &[4., 5.]);
+            assert_eq!(sliced_numbers.nulls(), vec![true, false, false, true]);
+            assert_eq!(
+                sliced_numbers.as_ref().as_ptr(),
+                numbers.as_ref()[3..].as_ptr()
+            );
+        } else {
+            unreachable!();
+        }
+
+        if let (FeatureDataRef::Decimal(decimals), FeatureDataRef::Decimal(sliced_decimals)) = (
+            multi_point.data("decimal").unwrap(),
+            sliced.data("decimal").unwrap(),
+        ) {
+            assert_eq!(sliced_decimals.as_ref(), &[3, 4, 5, 6]);
+            assert_eq!(
+                sliced_decimals.as_ref().as_ptr(),
+                decimals.as_ref()[3..].as_ptr()
+            );
+        } else {
+            unreachable!();
+        }
+
+        let mut counter = ByteSizeCounter::new();
+        multi_point.count_byte_size(&mut counter);
+        // only the rebased multi-point offsets are new
+        assert_eq!(sliced.count_byte_size(&mut counter), 5 * 4);
+    }
+
+    #[test]
+    fn sort_by_texts_and_numbers() {
+        let multi_point = MultiPointCollection::from_data(
+            (0..4).map(|i| vec![(f64::from(i), 0.).into()]).collect(),
+            vec![TimeInterval::default(); 4],
+            [
+                (
+                    "text".to_string(),
+                    FeatureData::NullableText(vec![
+                        Some("b".to_string()),
+                        None,
+                        Some("a".to_string()),
+                        Some("b".to_string()),
+                    ]),
+                ),
+                (
+                    "number".to_string(),
+                    FeatureData::Number(vec![1., f64::NAN, 3., 0.]),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let x_values = |collection: &MultiPointCollection| {
+            collection
+                .coordinates()
+                .iter()
+                .map(|c| c.x)
+                .collect::<Vec<_>>()
+        };
+
+        let sorted = multi_point
+            .sort_by(&[
+                SortOptions::ascending(SortKey::Column("text".to_string())),
+                SortOptions::ascending(SortKey::Column("number".to_string())),
+            ])
+            .unwrap();
+        assert_eq!(x_values(&sorted), vec![2., 3., 0., 1.]);
+
+        // NaNs are last in both directions
+        let sorted = multi_point
+            .sort_by(&[SortOptions::ascending(SortKey::Column(
+                "number".to_string(),
+            ))])
+            .unwrap();
+        assert_eq!(x_values(&sorted), vec![3., 0., 2., 1.]);
+
+        let sorted = multi_point
+            .sort_by(&[SortOptions::descending(SortKey::Column(
+                "number".to_string(),
+            ))])
+            .unwrap();
+        assert_eq!(x_values(&sorted), vec![2., 0., 3., 1.]);
+
+        assert!(multi_point
+            .sort_by(&[SortOptions::ascending(SortKey::Column(
+                "__time".to_string()
+            ))])
+            .is_err());
+        assert!(MultiPointCollection::empty()
+            .sort_by(&[SortOptions::ascending(SortKey::TimeStart)])
+            .unwrap()
+            .is_empty());
+        assert!(multi_point
+            .filter(vec![false; 4])
+            .unwrap()
+            .sort_by(&[SortOptions::ascending(SortKey::Column(
+                "number".to_string()
+            ))])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn filter_to_empty_keeps_columns() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+            vec![TimeInterval::default(); 2],
+            [("foo".to_string(), FeatureData::Number(vec![0., 1.]))]
+                .iter()
+                .cloned()
+                .collect(),
+        )
+        .unwrap();
+
+        let filtered = multi_point
+            .filter_bbox(
+                &BoundingBox2D::new((5., 5.).into(), (6., 6.).into()).unwrap(),
+                false,
+            )
+            .unwrap();
+
+        assert!(filtered.is_empty());
+        assert!(filtered.coordinates().is_empty());
+        assert!(filtered.time_intervals().is_empty());
+        assert!(filtered.data("foo").is_ok());
+        assert_eq!(filtered.append(&multi_point).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn join_on_multiple_keys() {
+        let left = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into()],
+                vec![(2., 2.).into()],
+            ],
+            vec![TimeInterval::default(); 3],
+            [
+                (
+                    "name".to_string(),
+                    FeatureData::NullableText(vec![Some("a".into()), Some("a".into()), None]),
+                ),
+                ("class".to_string(), FeatureData::Categorical(vec![1, 2, 1])),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let right = MultiPointCollection::from_data(
+            vec![vec![(5., 5.).into()], vec![(6., 6.).into()]],
+            vec![TimeInterval::default(); 2],
+            [
+                (
+                    "name".to_string(),
+                    FeatureData::Text(vec!["a".into(), "a".into()]),
+                ),
+                ("class".to_string(), FeatureData::Categorical(vec![1, 1])),
+                ("value".to_string(), FeatureData::Decimal(vec![10, 20])),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let inner = left
+            .join(&right, &["name", "class"], JoinType::Inner, "_r")
+            .unwrap();
+        assert_eq!(inner.coordinates(), &[(0., 0.).into(), (0., 0.).into()]);
+        assert_eq!(inner.column_types()["value"], FeatureDataType::Decimal);
+        if let FeatureDataRef::Decimal(values) = inner.data("value").unwrap() {
+            assert_eq!(values.as_ref(), &[10, 20]);
+        } else {
+            unreachable!();
+        }
+
+        let left_join = left
+            .join(&right, &["name", "class"], JoinType::Left, "_r")
+            .unwrap();
+        assert_eq!(left_join.len(), 4);
+        assert_eq!(
+            left_join.column_types()["value"],
+            FeatureDataType::NullableDecimal
+        );
+
+        let no_match = left
+            .join(
+                &right.filter(vec![false, false]).unwrap(),
+                &["name"],
+                JoinType::Inner,
+                "_r",
+            )
+            .unwrap();
+        assert!(no_match.is_empty());
+        assert!(no_match.data("value").is_ok());
+
+        assert!(left.join(&right, &[], JoinType::Inner, "_r").is_err());
+        assert!(left
+            .join(&right, &["value"], JoinType::Inner, "_r")
+            .is_err());
+    }
+
+    #[test]
+    fn group_by_with_nulls() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into()],
+                vec![(1., 1.).into(), (2., 2.).into()],
+                vec![(3., 3.).into()],
+                vec![(4., 4.).into()],
+            ],
+            vec![
+                TimeInterval::new_unchecked(2, 3),
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+                TimeInterval::new_unchecked(7, 8),
+            ],
+            [
+                (
+                    "class".to_string(),
+                    FeatureData::NullableDecimal(vec![Some(1), Some(1), Some(1), None]),
+                ),
+                (
+                    "value".to_string(),
+                    FeatureData::NullableDecimal(vec![Some(3), None, Some(5), None]),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let aggregates = [
+            Aggregate::new(AggregateFunction::Sum, "value", "sum"),
+            Aggregate::new(AggregateFunction::Min, "value", "min"),
+            Aggregate::new(AggregateFunction::First, "value", "first"),
+        ];
+
+        let grouped = multi_point
+            .group_by(&["class"], &aggregates, TimeMerge::Union)
+            .unwrap();
+
+        assert_eq!(grouped.multi_point_offsets(), &[0, 4, 5]);
+        assert_eq!(
+            grouped.time_intervals(),
+            &[
+                TimeInterval::new_unchecked(0, 3),
+                TimeInterval::new_unchecked(7, 8)
+            ]
+        );
+        assert_eq!(
+            grouped.column_types()["class"],
+            FeatureDataType::NullableDecimal
+        );
+        assert_eq!(
+            grouped.column_types()["sum"],
+            FeatureDataType::NullableDecimal
+        );
+        assert_eq!(
+            grouped.column_types()["min"],
+            FeatureDataType::NullableDecimal
+        );
+        // the second group has no values to sum
+        if let FeatureDataRef::NullableDecimal(sums) = grouped.data("sum").unwrap() {
+            assert_eq!(sums.as_ref()[0], 8);
+            assert_eq!(sums.nulls(), vec![false, true]);
+        } else {
+            unreachable!();
+        }
+        if let FeatureDataRef::NullableDecimal(mins) = grouped.data("min").unwrap() {
+            assert_eq!(mins.as_ref()[0], 3);
+            assert_eq!(mins.nulls(), vec![false, true]);
+        } else {
+            unreachable!();
+        }
+
+        let empty = multi_point
+            .filter(vec![false; 4])
+            .unwrap()
+            .group_by(&["class"], &aggregates, TimeMerge::Union)
+            .unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.column_types().len(), 4);
+
+        assert!(multi_point
+            .group_by(
+                &["class"],
+                &[Aggregate::new(AggregateFunction::Sum, "value", "class")],
+                TimeMerge::Hull
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn group_by_sum_overflow() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into()]; 3],
+            vec![TimeInterval::default(); 3],
+            [
+                ("class".to_string(), FeatureData::Decimal(vec![1, 1, 2])),
+                (
+                    "value".to_string(),
+                    FeatureData::Decimal(vec![i64::MAX, 1, i64::MAX]),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let sum = [Aggregate::new(AggregateFunction::Sum, "value", "sum")];
+
+        assert_eq!(
+            multi_point
+                .group_by(&["class"], &sum, TimeMerge::Hull)
+                .unwrap_err()
+                .to_string(),
+            "FeatureCollection exception: The sum of a group of decimals overflows"
+        );
+
+        let grouped = multi_point
+            .filter(vec![false, true, true])
+            .unwrap()
+            .group_by(&["class"], &sum, TimeMerge::Hull)
+            .unwrap();
+        if let FeatureDataRef::Decimal(sums) = grouped.data("sum").unwrap() {
+            assert_eq!(sums.as_ref(), &[1, i64::MAX]);
+        } else {
+            unreachable!();
+        }
+    }
+
+    #[test]
+    fn explode_drops_features_without_coordinates() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![
+                vec![],
+                vec![(1., 1.).into(), (2., 2.).into()],
+                vec![(3., 3.).into()],
+            ],
+            vec![TimeInterval::default(); 3],
+            [(
+                "foo".to_string(),
+                FeatureData::NullableText(vec![Some("a".into()), None, Some("c".into())]),
+            )]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let exploded = multi_point.explode().unwrap();
+
+        assert_eq!(exploded.len(), 3);
+        assert_eq!(exploded.multi_point_offsets(), &[0, 1, 2, 3]);
+        if let FeatureData::NullableText(texts) =
+            helpers::feature_data_from_ref(&exploded.data("foo").unwrap(), 3).unwrap()
+        {
+            assert_eq!(texts, vec![None, None, Some("c".to_string())]);
+        } else {
+            unreachable!();
+        }
+
+        let empty = multi_point.slice(0, 1).unwrap().explode().unwrap();
+        assert!(empty.is_empty());
+        assert!(empty.data("foo").is_ok());
+    }
+
+    #[test]
+    fn collect_by_inverts_explode() {
+        let multi_point = MultiPointCollection::from_data(
+            vec![
+                vec![(0., 0.).into(), (1., 1.).into()],
+                vec![(2., 2.).into()],
+                vec![(3., 3.).into(), (4., 4.).into()],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+                TimeInterval::new_unchecked(2, 3),
+            ],
+            [
+                (
+                    "cluster".to_string(),
+                    FeatureData::NullableCategorical(vec![Some(1), None, Some(2)]),
+                ),
+                (
+                    "name".to_string(),
+                    FeatureData::NullableText(vec![Some("a".into()), None, None]),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        let collected = multi_point
+            .explode()
+            .unwrap()
+            .collect_by("cluster", &HashMap::new(), TimeMerge::Union)
+            .unwrap();
+
+        assert_eq!(collected.coordinates(), multi_point.coordinates());
+        assert_eq!(
+            collected.multi_point_offsets(),
+            multi_point.multi_point_offsets()
+        );
+        assert_eq!(collected.time_intervals(), multi_point.time_intervals());
+        assert_eq!(
+            collected.column_types()["name"],
+            FeatureDataType::NullableText
+        );
+        if let FeatureData::NullableText(names) =
+            helpers::feature_data_from_ref(&collected.data("name").unwrap(), 3).unwrap()
+        {
+            assert_eq!(names, vec![Some("a".to_string()), None, None]);
+        } else {
+            unreachable!();
+        }
+
+        let empty = multi_point
+            .filter(vec![false; 3])
+            .unwrap()
+            .collect_by("cluster", &HashMap::new(), TimeMerge::Hull)
+            .unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.column_types().len(), 2);
+
+        assert!(multi_point
+            .collect_by("name", &HashMap::new(), TimeMerge::Hull)
+            .is_err());
+    }
+
+    #[test]
+    fn chunks_concat_to_input() {
+        let len = 10;
+        let multi_point = MultiPointCollection::from_data(
+            (0..len)
+                .map(|i| vec![(i as f64, 0.).into(); i % 2 + 1])
+                .collect(),
+            vec![TimeInterval::default(); len],
+            [
+                (
+                    "text".to_string(),
+                    FeatureData::Text((0..len).map(|i| "x".repeat(i)).collect()),
+                ),
+                (
+                    "number".to_string(),
+                    FeatureData::NullableNumber((0..len).map(|i| Some(i as f64)).collect()),
+                ),
+            ]
+            .iter()
+            .cloned()
+            .collect(),
+        )
+        .unwrap();
+
+        for &limit in &[
+            ChunkLimit::Features(1),
+            ChunkLimit::Features(3),
+            ChunkLimit::Features(100),
+            ChunkLimit::Bytes(0),
+            ChunkLimit::Bytes(100),
+        ] {
+            let chunks: Vec<MultiPointCollection> = multi_point.chunks(limit).unwrap().collect();
+            assert!(chunks.iter().all(|chunk| !chunk.is_empty()));
+
+            let concatenated = MultiPointCollection::concat(&chunks).unwrap();
+            assert_eq!(concatenated.coordinates(), multi_point.coordinates());
+            assert_eq!(
+                concatenated.multi_point_offsets(),
+                multi_point.multi_point_offsets()
+            );
+            assert_eq!(
+                helpers::feature_data_from_ref(&concatenated.data("number").unwrap(), len)
+                    .unwrap()
+                    .len(),
+                len
+            );
+        }
+
+        assert_eq!(
+            multi_point.chunks(ChunkLimit::Bytes(0)).unwrap().count(),
+            len
+        );
+
+        let chunk = multi_point
+            .chunks(ChunkLimit::Features(6))
+            .unwrap()
+            .nth(1)
+            .unwrap()
+            .slice(1, 2)
+            .unwrap();
+        assert_eq!(
+            chunk.coordinates().as_ptr(),
+            multi_point.coordinates()[10..].as_ptr()
+        );
+        assert_eq!(
+            chunk.to_wkt(),
+            vec!["MULTIPOINT ((7 0), (7 0))", "POINT (8 0)"]
+        );
+
+        let bytes = chunk.to_arrow_ipc(ArrowIpcFormat::Stream).unwrap();
+        let deserialized = MultiPointCollection::from_arrow_ipc(&bytes, ArrowIpcFormat::Stream)
+            .unwrap()
+            .slice(1, 1)
+            .unwrap();
+        assert_eq!(deserialized.to_wkt(), vec!["POINT (8 0)"]);
+        if let FeatureDataRef::Text(texts) = deserialized.data("text").unwrap() {
+            assert_eq!(texts.text_at(0).unwrap(), "x".repeat(8));
+        } else {
+            unreachable!();
+        }
+        assert!(multi_point.chunks(ChunkLimit::Features(0)).is_err());
+        assert_eq!(
+            multi_point
+                .filter(vec![false; len])
+                .unwrap()
+                .chunks(ChunkLimit::Features(1))
+                .unwrap()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn equality_ignores_memory_layout() {
+        let collection = |numbers: Vec<Option<f64>>, texts: Vec<Option<&str>>| {
+            MultiPointCollection::from_data(
+                vec![
+                    vec![(0., 0.).into()],
+                    vec![(1., 1.).into(), (2., 2.).into()],
+                    vec![(3., 3.).into()],
+                ],
+                vec![TimeInterval::new_unchecked(0, 1); 3],
+                {
+                    let mut map = HashMap::new();
+                    map.insert("numbers".into(), FeatureData::NullableNumber(numbers));
+                    map.insert(
+                        "texts".into(),
+                        FeatureData::NullableText(
+                            texts.into_iter().map(|t| t.map(str::to_string)).collect(),
+                        ),
+                    );
+                    map
+                },
+            )
+            .unwrap()
+        };
+
+        let pc = collection(
+            vec![Some(0.), None, Some(0.1 + 0.2)],
+            vec![Some("a"), None, None],
+        );
+
+        let bytes = pc
+            .slice(1, 2)
+            .unwrap()
+            .to_arrow_ipc(ArrowIpcFormat::File)
+            .unwrap();
+        let deserialized =
+            MultiPointCollection::from_arrow_ipc(&bytes, ArrowIpcFormat::File).unwrap();
+        assert_eq!(deserialized, pc.take(&[1, 2]).unwrap());
+        assert_eq!(
+            pc.filter(vec![false, true, true]).unwrap(),
+            pc.slice(1, 2).unwrap()
+        );
+
+        // a null differs from any value, even within a margin
+        let other = collection(
+            vec![Some(0.), Some(0.), Some(0.3)],
+            vec![Some("a"), None, None],
+        );
+        assert_ne!(pc, other);
+        assert!(pc.approx_ne(&other, (1., 2)));
+        assert!(pc
+            .slice(2, 1)
+            .unwrap()
+            .approx_eq(&other.slice(2, 1).unwrap(), (0., 2)));
+
+        // texts are compared exactly
+        let other = collection(
+            vec![Some(0.), None, Some(0.3)],
+            vec![Some("a"), None, Some("b")],
+        );
+        assert!(pc.approx_ne(&other, (0., 2)));
+
+        // column types must match
+        let other = pc
+            .remove_column("numbers")
+            .unwrap()
+            .add_column("numbers", FeatureData::Number(vec![0., 0., 0.3]))
+            .unwrap();
+        assert_ne!(pc, other);
+        assert_ne!(pc, pc.remove_column("numbers").unwrap());
+    }
+
+    #[test]
+    fn bool_columns() {
+        let mut builder = MultiPointCollection::builder();
+        builder.add_column("valid", FeatureDataType::Bool).unwrap();
+        builder
+            .add_column("checked", FeatureDataType::NullableBool)
+            .unwrap();
+        for (i, (valid, checked)) in [
+            (true, Some(false)),
+            (false, None),
+            (true, Some(true)),
+            (false, Some(true)),
+            (true, None),
+            (true, Some(true)),
+            (false, None),
+            (true, Some(false)),
+            (true, Some(true)),
+        ]
+        .iter()
+        .enumerate()
+        {
+            builder.append_coordinate((i as f64, 0.).into()).unwrap();
+            builder
+                .append_time_interval(TimeInterval::new_unchecked(0, 1))
+                .unwrap();
+            builder
+                .append_data("valid", FeatureDataValue::Bool(*valid))
+                .unwrap();
+            builder
+                .append_data("checked", FeatureDataValue::NullableBool(*checked))
+                .unwrap();
+            builder.finish_row().unwrap();
+        }
+        let pc = builder.build().unwrap();
+
+        let mask = match pc.data("valid").unwrap() {
+            FeatureDataRef::Bool(valid) => valid.to_mask(),
+            _ => unreachable!(),
+        };
+        let valid = pc.filter(mask).unwrap();
+        assert_eq!(valid.len(), 6);
+        assert_eq!(
+            helpers::feature_data_from_ref(&valid.data("checked").unwrap(), valid.len()).unwrap(),
+            FeatureData::NullableBool(vec![
+                Some(false),
+                Some(true),
+                None,
+                Some(true),
+                Some(false),
+                Some(true)
+            ])
+        );
+
+        // bits of a slice do not start at a byte boundary
+        let slice = pc.slice(3, 6).unwrap();
+        let checked = match slice.data("checked").unwrap() {
+            FeatureDataRef::NullableBool(checked) => checked.to_mask(),
+            _ => unreachable!(),
+        };
+        assert_eq!(checked, vec![true, false, true, false, false, true]);
+        assert_eq!(
+            slice.filter(checked).unwrap().to_wkt(),
+            vec!["POINT (3 0)", "POINT (5 0)", "POINT (8 0)"]
+        );
+
+        let sorted = slice
+            .sort_by(&[SortOptions::ascending(SortKey::Column("checked".into()))])
+            .unwrap();
+        assert!(matches!(
+            sorted.data("checked").unwrap().get(0).unwrap(),
+            FeatureDataValue::NullableBool(Some(false))
+        ));
+
+        assert_eq!(
+            MultiPointCollection::concat(&[pc.slice(0, 3).unwrap(), slice.clone()]).unwrap(),
+            pc
+        );
+
+        let bytes = slice.to_arrow_ipc(ArrowIpcFormat::Stream).unwrap();
+        assert_eq!(
+            MultiPointCollection::from_arrow_ipc(&bytes, ArrowIpcFormat::Stream).unwrap(),
+            slice
+        );
+
+        let geo_json = slice.to_geo_json().unwrap();
+        assert_eq!(
+            MultiPointCollection::from_geo_json(&geo_json, &GeoJsonTimeProperties::default())
+                .unwrap(),
+            slice
+        );
     }
 }
@@ -0,0 +1,1357 @@
+use std::collections::HashMap;
+
+use arrow::array::{
+    Array, ArrayBuilder, ArrayRef, BooleanArray, Date64Builder, FixedSizeListArray,
+    FixedSizeListBuilder, Float64Array, Float64Builder, ListArray, ListBuilder, StructArray,
+    StructBuilder,
+};
+use arrow::datatypes::{DataType, Field};
+use snafu::ensure;
+
+use crate::collections::{helpers, ByteSizeCounter, FeatureCollection};
+use crate::error;
+use crate::operations::{Filterable, Takeable};
+use crate::primitives::{
+    Coordinate2D, FeatureData, FeatureDataRef, FeatureDataType, FeatureDataValue, TimeInterval,
+};
+use crate::util::arrow::downcast_array;
+use crate::util::Result;
+use std::mem;
+use std::slice;
+use std::sync::Arc;
+
+#[derive(Debug)]
+pub struct MultiPolygonCollection {
+    data: StructArray,
+    types: HashMap<String, FeatureDataType>,
+}
+
+impl Clone for MultiPolygonCollection {
+    /// Clone the MultiPolygonCollection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    ///
+    /// let pc = MultiPolygonCollection::empty();
+    /// let cloned = pc.clone();
+    ///
+    /// assert_eq!(pc.len(), 0);
+    /// assert_eq!(cloned.len(), 0);
+    /// ```
+    ///
+    fn clone(&self) -> Self {
+        Self {
+            data: StructArray::from(self.data.data()),
+            types: self.types.clone(),
+        }
+    }
+}
+
+impl MultiPolygonCollection {
+    /// Retrieve the composite arrow data type for multi polygons
+    pub(self) fn multi_polygons_data_type() -> DataType {
+        DataType::List(
+            DataType::List(
+                DataType::List(DataType::FixedSizeList(DataType::Float64.into(), 2).into()).into(),
+            )
+            .into(),
+        )
+    }
+
+    /// Retrieve the composite arrow data type for time intervals
+    pub(self) fn time_data_type() -> DataType {
+        helpers::time_data_type()
+    }
+
+    /// Create an empty MultiPolygonCollection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    ///
+    /// let pc = MultiPolygonCollection::empty();
+    ///
+    /// assert_eq!(pc.len(), 0);
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            data: {
+                let columns = vec![
+                    Field::new(
+                        Self::FEATURE_COLUMN_NAME,
+                        Self::multi_polygons_data_type(),
+                        false,
+                    ),
+                    Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
+                ];
+
+                helpers::empty_struct_array(columns)
+            },
+            types: Default::default(),
+        }
+    }
+
+    /// Use a builder for creating the polygon collection
+    pub fn builder() -> MultiPolygonCollectionBuilder {
+        Default::default()
+    }
+
+    /// Create a polygon collection from data.
+    ///
+    /// Each polygon consists of rings where the first ring is the exterior ring and all further rings are holes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{Coordinate2D, TimeInterval, FeatureData};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![
+    ///         vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]],
+    ///         vec![vec![
+    ///             vec![(0., 0.).into(), (4., 0.).into(), (4., 4.).into(), (0., 0.).into()],
+    ///             vec![(1., 1.).into(), (2., 1.).into(), (2., 2.).into(), (1., 1.).into()],
+    ///         ]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.len(), 2);
+    /// ```
+    pub fn from_data(
+        coordinates: Vec<Vec<Vec<Vec<Coordinate2D>>>>,
+        time_intervals: Vec<TimeInterval>,
+        data: HashMap<String, FeatureData>,
+    ) -> Result<Self> {
+        let capacity = coordinates.len();
+
+        ensure!(
+            time_intervals.len() == capacity,
+            error::FeatureCollection {
+                details: "Number of time intervals must match the number of polygons"
+            }
+        );
+        ensure!(
+            coordinates.iter().flatten().all(|rings| !rings.is_empty()),
+            error::FeatureCollection {
+                details: "A polygon must consist of at least an exterior ring"
+            }
+        );
+        ensure!(
+            coordinates
+                .iter()
+                .flatten()
+                .flatten()
+                .all(|ring| is_valid_ring(ring)),
+            error::FeatureCollection {
+                details: "A ring must be closed and consist of at least four coordinates"
+            }
+        );
+
+        let mut columns = vec![
+            Field::new(
+                Self::FEATURE_COLUMN_NAME,
+                Self::multi_polygons_data_type(),
+                false,
+            ),
+            Field::new(Self::TIME_COLUMN_NAME, Self::time_data_type(), false),
+        ];
+
+        let mut builders: Vec<Box<dyn ArrayBuilder>> = vec![
+            Box::new({
+                let mut builder = ListBuilder::new(ListBuilder::new(ListBuilder::new(
+                    FixedSizeListBuilder::new(Float64Builder::new(2), 2),
+                )));
+                for multi_polygon in coordinates {
+                    let polygon_builder = builder.values();
+                    for polygon in multi_polygon {
+                        MultiPolygonCollectionBuilder::append_single_polygon_to_builder(
+                            polygon_builder,
+                            polygon,
+                        )?;
+                    }
+                    builder.append(true)?;
+                }
+
+                builder
+            }),
+            Box::new(helpers::time_intervals_builder(&time_intervals)?),
+        ];
+
+        let mut data_types = HashMap::with_capacity(data.len());
+
+        for (name, feature_data) in data {
+            ensure!(
+                !Self::is_reserved_name(&name),
+                error::ColumnNameConflict { name }
+            );
+
+            ensure!(
+                feature_data.len() == capacity,
+                error::FeatureCollection {
+                    details: format!(
+                        "Length of column {} must match the number of polygons",
+                        name
+                    )
+                }
+            );
+
+            let column = Field::new(
+                &name,
+                feature_data.arrow_data_type(),
+                feature_data.nullable(),
+            );
+
+            columns.push(column);
+            builders.push(feature_data.arrow_builder()?);
+
+            data_types.insert(name, FeatureDataType::from(&feature_data));
+        }
+
+        if capacity == 0 {
+            return Ok(Self {
+                data: helpers::empty_struct_array(columns),
+                types: data_types,
+            });
+        }
+
+        let mut struct_builder = StructBuilder::new(columns, builders);
+        for _ in 0..capacity {
+            struct_builder.append(true)?;
+        }
+
+        Ok(Self {
+            data: struct_builder.finish(),
+            types: data_types,
+        })
+    }
+
+    fn features(&self) -> &ListArray {
+        let features_ref = self
+            .data
+            .column_by_name(Self::FEATURE_COLUMN_NAME)
+            .expect("There must exist a feature column");
+        downcast_array(features_ref)
+    }
+
+    /// Retrieves the coordinates of all rings of this collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(
+    ///     pc.coordinates(),
+    ///     &[(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]
+    /// );
+    /// ```
+    ///
+    pub fn coordinates(&self) -> &[Coordinate2D] {
+        let polygons_ref = self.features().values();
+        let polygons: &ListArray = downcast_array(&polygons_ref);
+
+        let rings_ref = polygons.values();
+        let rings: &ListArray = downcast_array(&rings_ref);
+
+        let coordinates_ref = rings.values();
+        let coordinates: &FixedSizeListArray = downcast_array(&coordinates_ref);
+
+        let number_of_coordinates = coordinates.data().len();
+
+        let floats_ref = coordinates.values();
+        let floats: &Float64Array = downcast_array(&floats_ref);
+
+        unsafe {
+            slice::from_raw_parts(
+                floats.raw_values() as *const Coordinate2D,
+                number_of_coordinates,
+            )
+        }
+    }
+
+    /// Retrieves the offsets of the rings into `coordinates()`.
+    /// The ring `i` consists of the coordinates `offsets[i]..offsets[i + 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![vec![vec![
+    ///         vec![(0., 0.).into(), (4., 0.).into(), (4., 4.).into(), (0., 4.).into(), (0., 0.).into()],
+    ///         vec![(1., 1.).into(), (2., 1.).into(), (2., 2.).into(), (1., 1.).into()],
+    ///     ]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.ring_offsets(), &[0, 5, 9]);
+    /// ```
+    ///
+    pub fn ring_offsets(&self) -> &[i32] {
+        let polygons = &self.features().data_ref().child_data()[0];
+        let rings = &polygons.child_data()[0];
+
+        &rings.buffers()[0].typed_data::<i32>()[rings.offset()..=rings.offset() + rings.len()]
+    }
+
+    /// Retrieves the offsets of the polygons into `ring_offsets()`.
+    /// The polygon `i` consists of the rings `offsets[i]..offsets[i + 1]`.
+    /// The first ring of each polygon is its exterior ring, all further rings are holes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![vec![
+    ///         vec![
+    ///             vec![(0., 0.).into(), (4., 0.).into(), (4., 4.).into(), (0., 0.).into()],
+    ///             vec![(1., 1.).into(), (2., 1.).into(), (2., 2.).into(), (1., 1.).into()],
+    ///         ],
+    ///         vec![vec![(5., 5.).into(), (6., 5.).into(), (6., 6.).into(), (5., 5.).into()]],
+    ///     ]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.polygon_offsets(), &[0, 2, 3]);
+    /// ```
+    ///
+    pub fn polygon_offsets(&self) -> &[i32] {
+        let polygons = &self.features().data_ref().child_data()[0];
+
+        &polygons.buffers()[0].typed_data::<i32>()
+            [polygons.offset()..=polygons.offset() + polygons.len()]
+    }
+
+    /// Retrieves the offsets of the features into `polygon_offsets()`.
+    /// The feature `i` consists of the polygons `offsets[i]..offsets[i + 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![
+    ///         vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]],
+    ///         vec![
+    ///             vec![vec![(2., 2.).into(), (3., 2.).into(), (3., 3.).into(), (2., 2.).into()]],
+    ///             vec![vec![(4., 4.).into(), (5., 4.).into(), (5., 5.).into(), (4., 4.).into()]],
+    ///         ],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert_eq!(pc.multi_polygon_offsets(), &[0, 1, 3]);
+    /// ```
+    ///
+    pub fn multi_polygon_offsets(&self) -> &[i32] {
+        let features = self.features();
+
+        &features.data_ref().buffers()[0].typed_data::<i32>()
+            [features.offset()..=features.offset() + features.len()]
+    }
+}
+
+impl FeatureCollection for MultiPolygonCollection {
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// A polygon collection is simple if every feature consists of exactly one polygon
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use std::collections::HashMap;
+    ///
+    /// let simple = MultiPolygonCollection::from_data(
+    ///     vec![vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert!(simple.is_simple());
+    ///
+    /// let multi = MultiPolygonCollection::from_data(
+    ///     vec![vec![
+    ///         vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]],
+    ///         vec![vec![(2., 2.).into(), (3., 2.).into(), (3., 3.).into(), (2., 2.).into()]],
+    ///     ]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// assert!(!multi.is_simple());
+    /// ```
+    ///
+    fn is_simple(&self) -> bool {
+        self.multi_polygon_offsets()
+            .windows(2)
+            .all(|window| window[1] - window[0] == 1)
+    }
+
+    /// Retrieves a data column of this polygon collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     {
+    ///         let mut map = HashMap::new();
+    ///         map.insert("names".into(), FeatureData::Text(vec!["foo".into()]));
+    ///         map
+    ///     },
+    /// ).unwrap();
+    ///
+    /// if let FeatureDataRef::Text(names) = pc.data("names").unwrap() {
+    ///     assert_eq!(names.text_at(0).unwrap(), "foo");
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(pc.data("__time").is_err());
+    /// ```
+    ///
+    fn data(&self, column_name: &str) -> Result<FeatureDataRef> {
+        ensure!(
+            !Self::is_reserved_name(column_name),
+            error::FeatureCollection {
+                details: "Cannot access reserved columns via `data()` method"
+            }
+        );
+
+        helpers::feature_data_ref(&self.data, &self.types, column_name)
+    }
+
+    fn time_intervals(&self) -> &[TimeInterval] {
+        helpers::time_intervals_slice(&self.data, Self::TIME_COLUMN_NAME)
+    }
+
+    fn column_types(&self) -> &HashMap<String, FeatureDataType> {
+        &self.types
+    }
+
+    fn count_byte_size(&self, counter: &mut ByteSizeCounter) -> usize {
+        counter.count_struct_array(&self.data)
+    }
+
+    /// Extend the collection by an additional column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{FeatureData, TimeInterval, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]]],
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let extended = pc.add_column("foo", FeatureData::Categorical(vec![42])).unwrap();
+    ///
+    /// if let FeatureDataRef::Categorical(categories) = extended.data("foo").unwrap() {
+    ///     assert_eq!(categories.as_ref(), &[42]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    ///
+    /// assert!(extended.add_column("foo", FeatureData::Categorical(vec![42])).is_err());
+    /// assert!(extended.remove_column("foo").unwrap().data("foo").is_err());
+    /// ```
+    fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(new_column) && self.data.column_by_name(new_column).is_none(),
+            error::FeatureCollection {
+                details: "Cannot extend collection with name that is reserved or already in use"
+            }
+        );
+
+        ensure!(
+            data.len() == self.data.len(),
+            error::FeatureCollection {
+                details: "Length of new feature data column must match length of collection"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.insert(new_column.to_string(), FeatureDataType::from(&data));
+
+        Ok(Self {
+            data: helpers::struct_array_with_column(&self.data, new_column, &data)?,
+            types,
+        })
+    }
+
+    fn remove_column(&self, column: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+            error::FeatureCollection {
+                details: "Must not remove a non-existing or mandatory column"
+            }
+        );
+
+        let mut types = self.types.clone();
+        types.remove(column);
+
+        Ok(Self {
+            data: helpers::struct_array_without_column(&self.data, column),
+            types,
+        })
+    }
+
+    fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self> {
+        ensure!(
+            !Self::is_reserved_name(old_name) && self.data.column_by_name(old_name).is_some(),
+            error::FeatureCollection {
+                details: "Must not rename a non-existing or mandatory column"
+            }
+        );
+        ensure!(
+            !Self::is_reserved_name(new_name) && self.data.column_by_name(new_name).is_none(),
+            error::ColumnNameConflict { name: new_name }
+        );
+
+        let mut types = self.types.clone();
+        if let Some(data_type) = types.remove(old_name) {
+            types.insert(new_name.to_string(), data_type);
+        }
+
+        Ok(Self {
+            data: helpers::struct_array_with_renamed_column(&self.data, old_name, new_name),
+            types,
+        })
+    }
+
+    fn select_columns(&self, columns: &[&str]) -> Result<Self> {
+        for &column in columns {
+            ensure!(
+                !Self::is_reserved_name(column) && self.data.column_by_name(column).is_some(),
+                error::FeatureCollection {
+                    details: format!(
+                        "Cannot select the non-existing or mandatory column {}",
+                        column
+                    )
+                }
+            );
+        }
+
+        let types = self
+            .types
+            .iter()
+            .filter(|(column, _)| columns.contains(&column.as_str()))
+            .map(|(column, data_type)| (column.clone(), *data_type))
+            .collect();
+
+        Ok(Self {
+            data: helpers::struct_array_with_columns(&self.data, |column| {
+                Self::is_reserved_name(column) || columns.contains(&column)
+            }),
+            types,
+        })
+    }
+}
+
+impl Filterable for MultiPolygonCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use geoengine_datatypes::operations::Filterable;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![
+    ///         vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]],
+    ///         vec![vec![vec![(2., 2.).into(), (3., 2.).into(), (3., 3.).into(), (2., 2.).into()]]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let filtered = pc.filter(vec![false, true]).unwrap();
+    ///
+    /// assert_eq!(filtered.len(), 1);
+    /// assert_eq!(
+    ///     filtered.coordinates(),
+    ///     &[(2., 2.).into(), (3., 2.).into(), (3., 3.).into(), (2., 2.).into()]
+    /// );
+    /// assert_eq!(filtered.time_intervals(), &[TimeInterval::new_unchecked(1, 2)]);
+    /// ```
+    fn filter(&self, mask: Vec<bool>) -> Result<Self> {
+        ensure!(
+            mask.len() == self.data.len(),
+            error::MaskLengthDoesNotMatchCollectionLength {
+                mask_length: mask.len(),
+                collection_length: self.data.len(),
+            }
+        );
+
+        let filter_array: BooleanArray = mask.into();
+
+        Ok(Self {
+            data: helpers::filter_struct_array(
+                &self.data,
+                &filter_array,
+                Self::FEATURE_COLUMN_NAME,
+                |features, filter_array| {
+                    Ok(Arc::new(multi_polygons_filter(
+                        downcast_array(features),
+                        filter_array,
+                    )?))
+                },
+                Self::TIME_COLUMN_NAME,
+            )?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+impl Takeable for MultiPolygonCollection {
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    /// use geoengine_datatypes::operations::Takeable;
+    /// use std::collections::HashMap;
+    ///
+    /// let pc = MultiPolygonCollection::from_data(
+    ///     vec![
+    ///         vec![vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]],
+    ///         vec![vec![vec![(2., 2.).into(), (3., 2.).into(), (3., 3.).into(), (2., 2.).into()]]],
+    ///     ],
+    ///     vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///     HashMap::new(),
+    /// ).unwrap();
+    ///
+    /// let taken = pc.take(&[1]).unwrap();
+    ///
+    /// assert_eq!(taken.coordinates(), &pc.coordinates()[4..]);
+    /// assert_eq!(taken.time_intervals(), &[TimeInterval::new_unchecked(1, 2)]);
+    ///
+    /// let sliced = pc.slice(1, 1).unwrap();
+    ///
+    /// assert_eq!(sliced.coordinates(), &pc.coordinates()[4..]);
+    /// assert_eq!(sliced.multi_polygon_offsets(), &[0, 1]);
+    /// ```
+    fn take(&self, indices: &[usize]) -> Result<Self> {
+        Ok(Self {
+            data: helpers::take_struct_array(&self.data, indices)?,
+            types: self.types.clone(),
+        })
+    }
+
+    fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        Ok(Self {
+            data: helpers::slice_struct_array(&self.data, offset, len)?,
+            types: self.types.clone(),
+        })
+    }
+}
+
+fn multi_polygons_filter(features: &ListArray, filter_array: &BooleanArray) -> Result<ListArray> {
+    let polygons_ref: ArrayRef = features.values();
+    let polygons: &ListArray = downcast_array(&polygons_ref);
+
+    let rings_ref = polygons.values();
+    let rings: &ListArray = downcast_array(&rings_ref);
+
+    let coordinates_ref = rings.values();
+    let coordinates: &FixedSizeListArray = downcast_array(&coordinates_ref);
+
+    let floats_ref = coordinates.values();
+    let floats: &Float64Array = downcast_array(&floats_ref);
+
+    let mut new_features = ListBuilder::new(ListBuilder::new(ListBuilder::new(
+        FixedSizeListBuilder::new(Float64Builder::new(2), 2),
+    )));
+
+    for feature_index in 0..features.len() {
+        if !filter_array.value(feature_index) {
+            continue;
+        }
+
+        let polygon_builder = new_features.values();
+
+        let first_polygon = features.value_offset(feature_index) as usize;
+        let last_polygon = features.value_offset(feature_index + 1) as usize;
+
+        for polygon_index in first_polygon..last_polygon {
+            let ring_builder = polygon_builder.values();
+
+            let first_ring = polygons.value_offset(polygon_index) as usize;
+            let last_ring = polygons.value_offset(polygon_index + 1) as usize;
+
+            for ring_index in first_ring..last_ring {
+                let coordinate_builder = ring_builder.values();
+
+                let first_coordinate = rings.value_offset(ring_index) as usize;
+                let last_coordinate = rings.value_offset(ring_index + 1) as usize;
+
+                for coordinate_index in first_coordinate..last_coordinate {
+                    let float_builder = coordinate_builder.values();
+                    float_builder.append_slice(floats.value_slice(2 * coordinate_index, 2))?;
+
+                    coordinate_builder.append(true)?;
+                }
+
+                ring_builder.append(true)?;
+            }
+
+            polygon_builder.append(true)?;
+        }
+
+        new_features.append(true)?;
+    }
+
+    Ok(new_features.finish())
+}
+
+/// A row-by-row builder for a polygon collection
+pub struct MultiPolygonCollectionBuilder {
+    coordinates_builder:
+        ListBuilder<ListBuilder<ListBuilder<FixedSizeListBuilder<Float64Builder>>>>,
+    time_intervals_builder: FixedSizeListBuilder<Date64Builder>,
+    builders: HashMap<String, Box<dyn ArrayBuilder>>,
+    types: HashMap<String, FeatureDataType>,
+    rows: usize,
+}
+
+impl Default for MultiPolygonCollectionBuilder {
+    /// Creates a builder for a polygon collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    ///
+    /// let builder = MultiPolygonCollectionBuilder::default();
+    /// ```
+    ///
+    fn default() -> Self {
+        Self {
+            coordinates_builder: ListBuilder::new(ListBuilder::new(ListBuilder::new(
+                FixedSizeListBuilder::new(Float64Builder::new(0), 2),
+            ))),
+            time_intervals_builder: FixedSizeListBuilder::new(Date64Builder::new(0), 2),
+            builders: Default::default(),
+            types: Default::default(),
+            rows: 0,
+        }
+    }
+}
+
+impl MultiPolygonCollectionBuilder {
+    /// Adds a column to the collection.
+    /// Must happen before data insertions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    /// use geoengine_datatypes::primitives::FeatureDataType;
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    ///
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    /// builder.add_column("__time", FeatureDataType::Number).unwrap_err();
+    /// ```
+    ///
+    pub fn add_column(&mut self, name: &str, data_type: FeatureDataType) -> Result<()> {
+        ensure!(
+            self.rows == 0,
+            error::FeatureCollectionBuilderException {
+                details: "It is not allowed to add further columns after data was inserted",
+            }
+        );
+        ensure!(
+            !MultiPolygonCollection::is_reserved_name(name) && !self.types.contains_key(name),
+            error::ColumnNameConflict {
+                name: name.to_string()
+            }
+        );
+
+        self.builders
+            .insert(name.into(), data_type.arrow_builder(0));
+        self.types.insert(name.into(), data_type);
+
+        Ok(())
+    }
+
+    /// Finishes a row and checks for completion
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    ///
+    /// builder.append_polygon(vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_polygon(vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]).unwrap();
+    ///
+    /// builder.finish_row().unwrap_err();
+    /// ```
+    ///
+    pub fn finish_row(&mut self) -> Result<()> {
+        let rows = self.rows + 1;
+
+        ensure!(
+            self.coordinates_builder.len() == rows
+                && self.time_intervals_builder.len() == rows
+                && self.builders.values().all(|builder| builder.len() == rows),
+            error::FeatureCollectionBuilderException {
+                details: "Cannot finish row when child data is missing",
+            }
+        );
+
+        self.rows = rows;
+
+        Ok(())
+    }
+
+    /// Adds a single polygon to the builder.
+    /// The first ring is the exterior ring, all further rings are holes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    ///
+    /// // rings must be closed
+    /// builder.append_polygon(vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 1.).into()]]).unwrap_err();
+    ///
+    /// builder.append_polygon(vec![
+    ///     vec![(0., 0.).into(), (4., 0.).into(), (4., 4.).into(), (0., 0.).into()],
+    ///     vec![(1., 1.).into(), (2., 1.).into(), (2., 2.).into(), (1., 1.).into()],
+    /// ]).unwrap();
+    ///
+    /// builder.append_polygon(vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]]).unwrap_err();
+    /// ```
+    ///
+    pub fn append_polygon(&mut self, rings: Vec<Vec<Coordinate2D>>) -> Result<()> {
+        self.append_multi_polygon(vec![rings])
+    }
+
+    /// Adds a multi polygon to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    ///
+    /// // every polygon needs an exterior ring
+    /// builder.append_multi_polygon(vec![vec![]]).unwrap_err();
+    ///
+    /// builder.append_multi_polygon(vec![
+    ///     vec![vec![(0., 0.).into(), (1., 0.).into(), (1., 1.).into(), (0., 0.).into()]],
+    ///     vec![vec![(2., 2.).into(), (3., 2.).into(), (3., 3.).into(), (2., 2.).into()]],
+    /// ]).unwrap();
+    /// ```
+    ///
+    pub fn append_multi_polygon(&mut self, polygons: Vec<Vec<Vec<Coordinate2D>>>) -> Result<()> {
+        ensure!(
+            self.coordinates_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another polygon until row is finished",
+            }
+        );
+        ensure!(
+            polygons.iter().all(|rings| !rings.is_empty()),
+            error::FeatureCollectionBuilderException {
+                details: "A polygon must consist of at least an exterior ring",
+            }
+        );
+        ensure!(
+            polygons.iter().flatten().all(|ring| is_valid_ring(ring)),
+            error::FeatureCollectionBuilderException {
+                details: "A ring must be closed and consist of at least four coordinates",
+            }
+        );
+
+        let polygon_builder = self.coordinates_builder.values();
+        for polygon in polygons {
+            Self::append_single_polygon_to_builder(polygon_builder, polygon)?;
+        }
+
+        self.coordinates_builder.append(true)?;
+
+        Ok(())
+    }
+
+    fn append_single_polygon_to_builder(
+        polygon_builder: &mut ListBuilder<ListBuilder<FixedSizeListBuilder<Float64Builder>>>,
+        rings: Vec<Vec<Coordinate2D>>,
+    ) -> Result<()> {
+        let ring_builder = polygon_builder.values();
+        for ring in rings {
+            let coordinate_builder = ring_builder.values();
+            for coordinate in ring {
+                let float_builder = coordinate_builder.values();
+                float_builder.append_value(coordinate.x)?;
+                float_builder.append_value(coordinate.y)?;
+
+                coordinate_builder.append(true)?;
+            }
+
+            ring_builder.append(true)?;
+        }
+
+        polygon_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a time interval to the builder
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    /// use geoengine_datatypes::primitives::TimeInterval;
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    ///
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap_err();
+    /// ```
+    ///
+    pub fn append_time_interval(&mut self, time_interval: TimeInterval) -> Result<()> {
+        ensure!(
+            self.time_intervals_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another time interval until row is finished",
+            }
+        );
+
+        let date_builder = self.time_intervals_builder.values();
+        date_builder.append_value(time_interval.start())?;
+        date_builder.append_value(time_interval.end())?;
+
+        self.time_intervals_builder.append(true)?;
+
+        Ok(())
+    }
+
+    /// Adds a data item to the current row
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::MultiPolygonCollectionBuilder;
+    /// use geoengine_datatypes::primitives::{FeatureDataValue, FeatureDataType};
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    /// builder.add_column("foobar", FeatureDataType::NullableText).unwrap();
+    ///
+    /// builder.append_data("foobar", FeatureDataValue::Text("foo".into())).unwrap_err();
+    /// builder.append_data("foobar", FeatureDataValue::NullableText(None)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::NullableText(None)).unwrap_err();
+    /// ```
+    ///
+    pub fn append_data(&mut self, column: &str, data: FeatureDataValue) -> Result<()> {
+        ensure!(
+            self.types.contains_key(column),
+            error::FeatureCollectionBuilderException {
+                details: format!("Column {} does not exist", column),
+            }
+        );
+
+        let data_builder = self.builders.get_mut(column).unwrap(); // previously checked
+
+        ensure!(
+            data_builder.len() <= self.rows,
+            error::FeatureCollectionBuilderException {
+                details: "Cannot add another data item until row is finished",
+            }
+        );
+
+        let data_type = self.types.get(column).unwrap(); // previously checked
+
+        ensure!(
+            mem::discriminant(&FeatureDataType::from(&data)) == mem::discriminant(data_type), // same enum variant
+            error::FeatureCollectionBuilderException {
+                details: "Data type is wrong for the column",
+            }
+        );
+
+        helpers::append_feature_data_value(data_builder.as_mut(), data)
+    }
+
+    /// Builds the polygon collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPolygonCollectionBuilder, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureDataType, FeatureDataValue};
+    ///
+    /// let mut builder = MultiPolygonCollectionBuilder::default();
+    /// builder.add_column("foobar", FeatureDataType::Number).unwrap();
+    ///
+    /// builder.append_polygon(vec![
+    ///     vec![(0., 0.).into(), (4., 0.).into(), (4., 4.).into(), (0., 0.).into()],
+    ///     vec![(1., 1.).into(), (2., 1.).into(), (2., 2.).into(), (1., 1.).into()],
+    /// ]).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(0, 1)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(0.)).unwrap();
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// builder.append_multi_polygon(vec![
+    ///     vec![vec![(5., 5.).into(), (6., 5.).into(), (6., 6.).into(), (5., 5.).into()]],
+    ///     vec![vec![(7., 7.).into(), (8., 7.).into(), (8., 8.).into(), (7., 7.).into()]],
+    /// ]).unwrap();
+    /// builder.append_time_interval(TimeInterval::new_unchecked(1, 2)).unwrap();
+    /// builder.append_data("foobar", FeatureDataValue::Number(1.)).unwrap();
+    ///
+    /// builder.finish_row().unwrap();
+    ///
+    /// let polygon_collection = builder.build().unwrap();
+    ///
+    /// assert_eq!(polygon_collection.len(), 2);
+    /// assert_eq!(polygon_collection.coordinates().len(), 16);
+    /// assert_eq!(polygon_collection.ring_offsets(), &[0, 4, 8, 12, 16]);
+    /// assert_eq!(polygon_collection.polygon_offsets(), &[0, 2, 3, 4]);
+    /// assert_eq!(polygon_collection.multi_polygon_offsets(), &[0, 1, 3]);
+    /// ```
+    ///
+    pub fn build(mut self) -> Result<MultiPolygonCollection> {
+        ensure!(
+            self.coordinates_builder.len() == self.rows
+                && self.time_intervals_builder.len() == self.rows
+                && self
+                    .builders
+                    .values()
+                    .all(|builder| builder.len() == self.rows),
+            error::FeatureCollectionBuilderException {
+                details: "Cannot build a polygon collection out of unfinished rows",
+            }
+        );
+
+        let mut columns = Vec::with_capacity(self.types.len() + 2);
+        let mut builders: Vec<Box<dyn ArrayBuilder>> = Vec::with_capacity(self.types.len() + 2);
+
+        columns.push(Field::new(
+            MultiPolygonCollection::FEATURE_COLUMN_NAME,
+            MultiPolygonCollection::multi_polygons_data_type(),
+            false,
+        ));
+        builders.push(Box::new(self.coordinates_builder));
+
+        columns.push(Field::new(
+            MultiPolygonCollection::TIME_COLUMN_NAME,
+            MultiPolygonCollection::time_data_type(),
+            false,
+        ));
+        builders.push(Box::new(self.time_intervals_builder));
+
+        for (column_name, builder) in self.builders.drain() {
+            let column_type = self.types.get(&column_name).unwrap(); // column must exist
+            columns.push(Field::new(
+                &column_name,
+                column_type.arrow_data_type(),
+                column_type.nullable(),
+            ));
+            builders.push(builder);
+        }
+
+        if self.rows == 0 {
+            return Ok(MultiPolygonCollection {
+                data: helpers::empty_struct_array(columns),
+                types: self.types,
+            });
+        }
+
+        let mut struct_builder = StructBuilder::new(columns, builders);
+
+        for _ in 0..self.rows {
+            struct_builder.append(true)?;
+        }
+
+        Ok(MultiPolygonCollection {
+            data: struct_builder.finish(),
+            types: self.types,
+        })
+    }
+}
+
+/// A ring must be closed and consist of at least four coordinates
+fn is_valid_ring(ring: &[Coordinate2D]) -> bool {
+    ring.len() >= 4 && ring.first() == ring.last()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clone() {
+        let pc = MultiPolygonCollection::from_data(
+            vec![vec![vec![vec![
+                (0., 0.).into(),
+                (1., 0.).into(),
+                (1., 1.).into(),
+                (0., 0.).into(),
+            ]]]],
+            vec![TimeInterval::new_unchecked(0, 1)],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0.]));
+                map
+            },
+        )
+        .unwrap();
+
+        let cloned = pc.clone();
+
+        assert_eq!(pc.len(), cloned.len());
+        assert_eq!(pc.coordinates(), cloned.coordinates());
+        assert_eq!(pc.ring_offsets(), cloned.ring_offsets());
+    }
+
+    #[test]
+    fn filter_keeps_holes() {
+        let pc = MultiPolygonCollection::from_data(
+            vec![
+                vec![vec![vec![
+                    (0., 0.).into(),
+                    (1., 0.).into(),
+                    (1., 1.).into(),
+                    (0., 0.).into(),
+                ]]],
+                vec![vec![
+                    vec![
+                        (0., 0.).into(),
+                        (4., 0.).into(),
+                        (4., 4.).into(),
+                        (0., 4.).into(),
+                        (0., 0.).into(),
+                    ],
+                    vec![
+                        (1., 1.).into(),
+                        (2., 1.).into(),
+                        (2., 2.).into(),
+                        (1., 1.).into(),
+                    ],
+                ]],
+            ],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            {
+                let mut map = HashMap::new();
+                map.insert(
+                    "name".into(),
+                    FeatureData::NullableText(vec![Some("foo".into()), None]),
+                );
+                map
+            },
+        )
+        .unwrap();
+
+        let filtered = pc.filter(vec![false, true]).unwrap();
+
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered.multi_polygon_offsets(), &[0, 1]);
+        assert_eq!(filtered.polygon_offsets(), &[0, 2]);
+        assert_eq!(filtered.ring_offsets(), &[0, 5, 9]);
+        assert_eq!(filtered.coordinates()[5], (1., 1.).into());
+        assert_eq!(
+            filtered.time_intervals(),
+            &[TimeInterval::new_unchecked(1, 2)]
+        );
+        assert!(filtered.is_simple());
+    }
+
+    #[test]
+    fn invalid_polygons() {
+        let exterior: Vec<Coordinate2D> = vec![
+            (0., 0.).into(),
+            (4., 0.).into(),
+            (4., 4.).into(),
+            (0., 0.).into(),
+        ];
+        let unclosed = exterior[..3].to_vec();
+        let too_short = vec![(0., 0.).into(), (1., 1.).into(), (0., 0.).into()];
+
+        for polygon in &[
+            vec![unclosed],
+            vec![too_short],
+            vec![exterior.clone(), exterior[1..].to_vec()],
+            vec![],
+        ] {
+            let mut builder = MultiPolygonCollection::builder();
+            assert!(builder.append_polygon(polygon.clone()).is_err());
+            assert!(builder
+                .append_multi_polygon(vec![vec![exterior.clone()], polygon.clone()])
+                .is_err());
+
+            assert!(MultiPolygonCollection::from_data(
+                vec![vec![polygon.clone()]],
+                vec![TimeInterval::default()],
+                HashMap::new(),
+            )
+            .is_err());
+        }
+
+        assert!(MultiPolygonCollection::from_data(
+            vec![vec![vec![exterior]]],
+            vec![TimeInterval::default()],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0., 1.]));
+                map
+            },
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn empty_input() {
+        let from_data = MultiPolygonCollection::from_data(vec![], vec![], {
+            let mut map = HashMap::new();
+            map.insert("text".into(), FeatureData::NullableText(vec![]));
+            map
+        })
+        .unwrap();
+
+        let mut builder = MultiPolygonCollection::builder();
+        builder
+            .add_column("text", FeatureDataType::NullableText)
+            .unwrap();
+        let built = builder.build().unwrap();
+
+        for collection in &[from_data, built] {
+            assert!(collection.is_empty());
+            assert!(collection.coordinates().is_empty());
+            assert_eq!(collection.ring_offsets(), &[0]);
+            assert_eq!(collection.polygon_offsets(), &[0]);
+            assert_eq!(collection.multi_polygon_offsets(), &[0]);
+            assert!(collection.data("text").is_ok());
+        }
+    }
+
+    #[test]
+    fn slice_and_take() {
+        let triangle = |x: f64| -> Vec<Coordinate2D> {
+            vec![
+                (x, 0.).into(),
+                (x + 1., 0.).into(),
+                (x + 1., 1.).into(),
+                (x, 0.).into(),
+            ]
+        };
+        let hole: Vec<Coordinate2D> = vec![
+            (1., 1.).into(),
+            (2., 1.).into(),
+            (2., 2.).into(),
+            (1., 1.).into(),
+        ];
+        let square_with_hole = vec![
+            vec![
+                (0., 0.).into(),
+                (4., 0.).into(),
+                (4., 4.).into(),
+                (0., 4.).into(),
+                (0., 0.).into(),
+            ],
+            hole.clone(),
+        ];
+
+        let pc = MultiPolygonCollection::from_data(
+            vec![
+                vec![vec![triangle(0.)]],
+                vec![square_with_hole],
+                vec![vec![triangle(5.)], vec![triangle(7.)]],
+            ],
+            vec![TimeInterval::default(); 3],
+            {
+                let mut map = HashMap::new();
+                map.insert("number".into(), FeatureData::Number(vec![0., 1., 2.]));
+                map
+            },
+        )
+        .unwrap();
+
+        let sliced = pc.slice(1, 2).unwrap();
+
+        assert_eq!(sliced.multi_polygon_offsets(), &[0, 1, 3]);
+        assert_eq!(sliced.polygon_offsets(), &[0, 2, 3, 4]);
+        assert_eq!(sliced.ring_offsets(), &[0, 5, 9, 13, 17]);
+        assert_eq!(sliced.coordinates(), &pc.coordinates()[4..]);
+
+        let sliced = sliced.slice(1, 1).unwrap();
+
+        assert_eq!(sliced.multi_polygon_offsets(), &[0, 2]);
+        assert_eq!(sliced.polygon_offsets(), &[0, 1, 2]);
+        assert_eq!(sliced.ring_offsets(), &[0, 4, 8]);
+        assert_eq!(sliced.coordinates(), &pc.coordinates()[13..]);
+
+        let taken = pc.take(&[1, 2, 1]).unwrap();
+
+        assert_eq!(taken.multi_polygon_offsets(), &[0, 1, 3, 4]);
+        assert_eq!(taken.polygon_offsets(), &[0, 2, 3, 4, 6]);
+        assert_eq!(taken.ring_offsets(), &[0, 5, 9, 13, 17, 22, 26]);
+        assert_eq!(&taken.coordinates()[5..9], hole.as_slice());
+        assert_eq!(&taken.coordinates()[22..], hole.as_slice());
+        if let FeatureDataRef::Number(numbers) = taken.data("number").unwrap() {
+            assert_eq!(numbers.as_ref(), &[1., 2., 1.]);
+        } else {
+            unreachable!();
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::cmp::Ordering;
+
+use snafu::ensure;
+
+use crate::collections::{helpers, FeatureCollection};
+use crate::error;
+use crate::primitives::FeatureData;
+use crate::util::Result;
+
+/// The values to sort a collection by
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SortKey {
+    /// An attribute column
+    Column(String),
+    /// The start of the time intervals
+    TimeStart,
+    /// The end of the time intervals
+    TimeEnd,
+}
+
+/// A sort key together with its direction and the placement of null values
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SortOptions {
+    pub key: SortKey,
+    pub descending: bool,
+    pub nulls_first: bool,
+}
+
+impl SortOptions {
+    /// Sort ascending by `key` with null values last
+    pub fn ascending(key: SortKey) -> Self {
+        Self {
+            key,
+            descending: false,
+            nulls_first: false,
+        }
+    }
+
+    /// Sort descending by `key` with null values last
+    pub fn descending(key: SortKey) -> Self {
+        Self {
+            key,
+            descending: true,
+            nulls_first: false,
+        }
+    }
+
+    /// Place null values before all other values
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls_first = true;
+        self
+    }
+}
+
+/// The values of a sort key, where missing values are nulls
+enum SortValues {
+    Numbers(Vec<Option<f64>>),
+    Decimals(Vec<Option<i64>>),
+    Categories(Vec<Option<u8>>),
+    Texts(Vec<Option<String>>),
+    Bools(Vec<Option<bool>>),
+    Times(Vec<i64>),
+}
+
+impl SortValues {
+    fn new<C: FeatureCollection>(collection: &C, key: &SortKey) -> Result<Self> {
+        fn all_some<T>(values: Vec<T>) -> Vec<Option<T>> {
+            values.into_iter().map(Some).collect()
+        }
+
+        let column = match key {
+            SortKey::Column(column) => column,
+            SortKey::TimeStart => {
+                return Ok(SortValues::Times(
+                    collection
+                        .time_intervals()
+                        .iter()
+                        .map(|time_interval| time_interval.start())
+                        .collect(),
+                ))
+            }
+            SortKey::TimeEnd => {
+                return Ok(SortValues::Times(
+                    collection
+                        .time_intervals()
+                        .iter()
+                        .map(|time_interval| time_interval.end())
+                        .collect(),
+                ))
+            }
+        };
+
+        ensure!(
+            !C::is_reserved_name(column),
+            error::FeatureCollection {
+                details: format!("Cannot sort by the reserved column {}", column),
+            }
+        );
+
+        let data = helpers::feature_data_from_ref(&collection.data(column)?, collection.len())?;
+
+        Ok(match data {
+            FeatureData::Number(values) => SortValues::Numbers(all_some(values)),
+            FeatureData::NullableNumber(values) => SortValues::Numbers(values),
+            FeatureData::Decimal(values) => SortValues::Decimals(all_some(values)),
+            FeatureData::NullableDecimal(values) => SortValues::Decimals(values),
+            FeatureData::Categorical(values) => SortValues::Categories(all_some(values)),
+            FeatureData::NullableCategorical(values) => SortValues::Categories(values),
+            FeatureData::Text(values) => SortValues::Texts(all_some(values)),
+            FeatureData::NullableText(values) => SortValues::Texts(values),
+            FeatureData::Bool(values) => SortValues::Bools(all_some(values)),
+            FeatureData::NullableBool(values) => SortValues::Bools(values),
+        })
+    }
+
+    fn compare(&self, a: usize, b: usize, options: &SortOptions) -> Ordering {
+        fn compare_options<T>(
+            a: &Option<T>,
+            b: &Option<T>,
+            options: &SortOptions,
+            compare: impl Fn(&T, &T) -> Ordering,
+        ) -> Ordering {
+            match (a, b) {
+                (Some(a), Some(b)) if options.descending => compare(a, b).reverse(),
+                (Some(a), Some(b)) => compare(a, b),
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) if options.nulls_first => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) if options.nulls_first => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+            }
+        }
+
+        match self {
+            // NaNs are placed after all other numbers regardless of the direction
+            SortValues::Numbers(values) => match (values[a], values[b]) {
+                (Some(a), Some(b)) if a.is_nan() || b.is_nan() => a.is_nan().cmp(&b.is_nan()),
+                (a, b) => compare_options(&a, &b, options, |a, b| {
+                    a.partial_cmp(b).expect("NaNs are compared before")
+                }),
+            },
+            SortValues::Decimals(values) => {
+                compare_options(&values[a], &values[b], options, Ord::cmp)
+            }
+            SortValues::Categories(values) => {
+                compare_options(&values[a], &values[b], options, Ord::cmp)
+            }
+            SortValues::Texts(values) => compare_options(&values[a], &values[b], options, Ord::cmp),
+            SortValues::Bools(values) => compare_options(&values[a], &values[b], options, Ord::cmp),
+            SortValues::Times(values) => {
+                compare_options(&Some(values[a]), &Some(values[b]), options, Ord::cmp)
+            }
+        }
+    }
+}
+
+/// Computes the order of the features of `collection` according to `sort_options`.
+///
+/// Later options break ties of earlier ones and the sort is stable.
+pub(super) fn sorted_indices<C: FeatureCollection>(
+    collection: &C,
+    sort_options: &[SortOptions],
+) -> Result<Vec<usize>> {
+    let sort_values = sort_options
+        .iter()
+        .map(|options| SortValues::new(collection, &options.key))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut indices: Vec<usize> = (0..collection.len()).collect();
+    indices.sort_by(|&a, &b| {
+        sort_values
+            .iter()
+            .zip(sort_options)
+            .map(|(values, options)| values.compare(a, b, options))
+            .find(|&ordering| ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    Ok(indices)
+}
@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::collections::{
+    ByteSizeCounter, DataCollection, FeatureCollection, MultiLineStringCollection,
+    MultiPointCollection, MultiPolygonCollection,
+};
+use crate::operations::{Filterable, Takeable};
+use crate::primitives::{FeatureData, FeatureDataRef, FeatureDataType, TimeInterval};
+use crate::util::Result;
+
+/// The geometry type of a feature collection
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum VectorDataType {
+    Data,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon,
+}
+
+/// A feature collection whose geometry type is only known at runtime
+#[derive(Clone, Debug)]
+pub enum TypedFeatureCollection {
+    Data(DataCollection),
+    MultiPoint(MultiPointCollection),
+    MultiLineString(MultiLineStringCollection),
+    MultiPolygon(MultiPolygonCollection),
+}
+
+/// Apply an expression to the collection inside any variant
+macro_rules! call_on_collection {
+    ($typed_collection:expr, $collection:ident => $expression:expr) => {
+        match $typed_collection {
+            TypedFeatureCollection::Data($collection) => $expression,
+            TypedFeatureCollection::MultiPoint($collection) => $expression,
+            TypedFeatureCollection::MultiLineString($collection) => $expression,
+            TypedFeatureCollection::MultiPolygon($collection) => $expression,
+        }
+    };
+}
+
+/// Apply an expression that yields a new collection and wrap it into the same variant
+macro_rules! map_collection {
+    ($typed_collection:expr, $collection:ident => $expression:expr) => {
+        match $typed_collection {
+            TypedFeatureCollection::Data($collection) => TypedFeatureCollection::Data($expression),
+            TypedFeatureCollection::MultiPoint($collection) => {
+                TypedFeatureCollection::MultiPoint($expression)
+            }
+            TypedFeatureCollection::MultiLineString($collection) => {
+                TypedFeatureCollection::MultiLineString($expression)
+            }
+            TypedFeatureCollection::MultiPolygon($collection) => {
+                TypedFeatureCollection::MultiPolygon($expression)
+            }
+        }
+    };
+}
+
+impl TypedFeatureCollection {
+    /// Retrieve the geometry type of the wrapped collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{
+    ///     DataCollection, MultiPointCollection, TypedFeatureCollection, VectorDataType,
+    /// };
+    ///
+    /// let points: TypedFeatureCollection = MultiPointCollection::empty().into();
+    /// let data: TypedFeatureCollection = DataCollection::empty().into();
+    ///
+    /// assert_eq!(points.vector_data_type(), VectorDataType::MultiPoint);
+    /// assert_eq!(data.vector_data_type(), VectorDataType::Data);
+    /// ```
+    pub fn vector_data_type(&self) -> VectorDataType {
+        match self {
+            TypedFeatureCollection::Data(_) => VectorDataType::Data,
+            TypedFeatureCollection::MultiPoint(_) => VectorDataType::MultiPoint,
+            TypedFeatureCollection::MultiLineString(_) => VectorDataType::MultiLineString,
+            TypedFeatureCollection::MultiPolygon(_) => VectorDataType::MultiPolygon,
+        }
+    }
+
+    /// Retrieve the wrapped collection if it is a `DataCollection`
+    pub fn get_data(&self) -> Option<&DataCollection> {
+        if let TypedFeatureCollection::Data(collection) = self {
+            Some(collection)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the wrapped collection if it is a `MultiPointCollection`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, TypedFeatureCollection};
+    ///
+    /// let collection = TypedFeatureCollection::from(MultiPointCollection::empty());
+    ///
+    /// assert!(collection.get_multi_points().is_some());
+    /// assert!(collection.get_multi_polygons().is_none());
+    /// ```
+    pub fn get_multi_points(&self) -> Option<&MultiPointCollection> {
+        if let TypedFeatureCollection::MultiPoint(collection) = self {
+            Some(collection)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the wrapped collection if it is a `MultiLineStringCollection`
+    pub fn get_multi_line_strings(&self) -> Option<&MultiLineStringCollection> {
+        if let TypedFeatureCollection::MultiLineString(collection) = self {
+            Some(collection)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieve the wrapped collection if it is a `MultiPolygonCollection`
+    pub fn get_multi_polygons(&self) -> Option<&MultiPolygonCollection> {
+        if let TypedFeatureCollection::MultiPolygon(collection) = self {
+            Some(collection)
+        } else {
+            None
+        }
+    }
+}
+
+impl FeatureCollection for TypedFeatureCollection {
+    fn len(&self) -> usize {
+        call_on_collection!(self, c => c.len())
+    }
+
+    fn is_simple(&self) -> bool {
+        call_on_collection!(self, c => c.is_simple())
+    }
+
+    /// Retrieves a data column of the wrapped collection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{MultiPointCollection, TypedFeatureCollection, FeatureCollection};
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData, FeatureDataRef};
+    /// use std::collections::HashMap;
+    ///
+    /// let collections: Vec<TypedFeatureCollection> = vec![
+    ///     MultiPointCollection::from_data(
+    ///         vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+    ///         vec![TimeInterval::new_unchecked(0, 1), TimeInterval::new_unchecked(1, 2)],
+    ///         {
+    ///             let mut map = HashMap::new();
+    ///             map.insert("foo".into(), FeatureData::Number(vec![0., 1.]));
+    ///             map
+    ///         },
+    ///     ).unwrap().into(),
+    /// ];
+    ///
+    /// assert_eq!(collections[0].len(), 2);
+    /// assert_eq!(collections[0].time_intervals().len(), 2);
+    ///
+    /// if let FeatureDataRef::Number(numbers) = collections[0].data("foo").unwrap() {
+    ///     assert_eq!(numbers.as_ref(), &[0., 1.]);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    fn data(&self, column: &str) -> Result<FeatureDataRef> {
+        call_on_collection!(self, c => c.data(column))
+    }
+
+    fn time_intervals(&self) -> &[TimeInterval] {
+        call_on_collection!(self, c => c.time_intervals())
+    }
+
+    fn column_types(&self) -> &HashMap<String, FeatureDataType> {
+        call_on_collection!(self, c => c.column_types())
+    }
+
+    fn count_byte_size(&self, counter: &mut ByteSizeCounter) -> usize {
+        call_on_collection!(self, c => c.count_byte_size(counter))
+    }
+
+    /// Extend the wrapped collection by an additional column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::collections::{
+    ///     DataCollection, TypedFeatureCollection, FeatureCollection, VectorDataType,
+    /// };
+    /// use geoengine_datatypes::primitives::{TimeInterval, FeatureData};
+    /// use std::collections::HashMap;
+    ///
+    /// let collection: TypedFeatureCollection = DataCollection::from_data(
+    ///     vec![TimeInterval::new_unchecked(0, 1)],
+    ///     HashMap::new(),
+    /// ).unwrap().into();
+    ///
+    /// let extended = collection.add_column("foo", FeatureData::Number(vec![1.])).unwrap();
+    ///
+    /// assert_eq!(extended.vector_data_type(), VectorDataType::Data);
+    /// assert!(extended.data("foo").is_ok());
+    /// assert!(extended.remove_column("foo").unwrap().data("foo").is_err());
+    /// ```
+    fn add_column(&self, new_column: &str, data: FeatureData) -> Result<Self> {
+        Ok(map_collection!(self, c => c.add_column(new_column, data)?))
+    }
+
+    fn remove_column(&self, column: &str) -> Result<Self> {
+        Ok(map_collection!(self, c => c.remove_column(column)?))
+    }
+
+    fn rename_column(&self, old_name: &str, new_name: &str) -> Result<Self> {
+        Ok(map_collection!(self, c => c.rename_column(old_name, new_name)?))
+    }
+
+    fn select_columns(&self, columns: &[&str]) -> Result<Self> {
+        Ok(map_collection!(self, c => c.select_columns(columns)?))
+    }
+}
+
+impl Filterable for TypedFeatureCollection {
+    fn filter(&self, mask: Vec<bool>) -> Result<Self> {
+        Ok(map_collection!(self, c => c.filter(mask)?))
+    }
+}
+
+impl Takeable for TypedFeatureCollection {
+    fn take(&self, indices: &[usize]) -> Result<Self> {
+        Ok(map_collection!(self, c => c.take(indices)?))
+    }
+
+    fn slice(&self, offset: usize, len: usize) -> Result<Self> {
+        Ok(map_collection!(self, c => c.slice(offset, len)?))
+    }
+}
+
+impl From<DataCollection> for TypedFeatureCollection {
+    fn from(collection: DataCollection) -> Self {
+        TypedFeatureCollection::Data(collection)
+    }
+}
+
+impl From<MultiPointCollection> for TypedFeatureCollection {
+    fn from(collection: MultiPointCollection) -> Self {
+        TypedFeatureCollection::MultiPoint(collection)
+    }
+}
+
+impl From<MultiLineStringCollection> for TypedFeatureCollection {
+    fn from(collection: MultiLineStringCollection) -> Self {
+        TypedFeatureCollection::MultiLineString(collection)
+    }
+}
+
+impl From<MultiPolygonCollection> for TypedFeatureCollection {
+    fn from(collection: MultiPolygonCollection) -> Self {
+        TypedFeatureCollection::MultiPolygon(collection)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn mixed_collections() {
+        let collections: Vec<TypedFeatureCollection> = vec![
+            MultiPointCollection::from_data(
+                vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+                vec![
+                    TimeInterval::new_unchecked(0, 1),
+                    TimeInterval::new_unchecked(1, 2),
+                ],
+                HashMap::new(),
+            )
+            .unwrap()
+            .into(),
+            MultiLineStringCollection::from_data(
+                vec![vec![vec![(0., 0.).into(), (1., 1.).into()]]],
+                vec![TimeInterval::new_unchecked(0, 1)],
+                HashMap::new(),
+            )
+            .unwrap()
+            .into(),
+            DataCollection::from_data(vec![TimeInterval::new_unchecked(0, 1)], HashMap::new())
+                .unwrap()
+                .into(),
+        ];
+
+        assert_eq!(
+            collections
+                .iter()
+                .map(TypedFeatureCollection::vector_data_type)
+                .collect::<Vec<_>>(),
+            vec![
+                VectorDataType::MultiPoint,
+                VectorDataType::MultiLineString,
+                VectorDataType::Data
+            ]
+        );
+        assert_eq!(
+            collections.iter().map(|c| c.len()).collect::<Vec<_>>(),
+            vec![2, 1, 1]
+        );
+    }
+
+    #[test]
+    fn filter_keeps_type() {
+        let collection: TypedFeatureCollection = MultiPointCollection::from_data(
+            vec![vec![(0., 0.).into()], vec![(1., 1.).into()]],
+            vec![
+                TimeInterval::new_unchecked(0, 1),
+                TimeInterval::new_unchecked(1, 2),
+            ],
+            HashMap::new(),
+        )
+        .unwrap()
+        .into();
+
+        let filtered = collection.filter(vec![false, true]).unwrap();
+
+        assert_eq!(filtered.vector_data_type(), VectorDataType::MultiPoint);
+        assert_eq!(
+            filtered.time_intervals(),
+            &[TimeInterval::new_unchecked(1, 2)]
+        );
+        assert!(collection.filter(vec![true]).is_err());
+    }
+}
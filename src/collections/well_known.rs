@@ -0,0 +1,266 @@
+use std::convert::TryInto;
+
+use snafu::ensure;
+
+use crate::error;
+use crate::primitives::Coordinate2D;
+use crate::util::Result;
+
+const WKB_BIG_ENDIAN: u8 = 0;
+const WKB_LITTLE_ENDIAN: u8 = 1;
+const WKB_POINT: u32 = 1;
+const WKB_MULTI_POINT: u32 = 4;
+/// The number of bytes of a `Point` without SRID
+const WKB_POINT_SIZE: usize = 21;
+/// The flag of PostGIS' extended WKB that indicates an embedded SRID
+const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+
+/// Writes the coordinates of a feature as WKT.
+///
+/// A single coordinate becomes a `POINT`, all other coordinate lists a `MULTIPOINT`.
+pub(super) fn multi_point_to_wkt(coordinates: &[Coordinate2D]) -> String {
+    match coordinates {
+        [] => "MULTIPOINT EMPTY".to_string(),
+        [coordinate] => format!("POINT ({} {})", coordinate.x, coordinate.y),
+        coordinates => format!(
+            "MULTIPOINT ({})",
+            coordinates
+                .iter()
+                .map(|coordinate| format!("({} {})", coordinate.x, coordinate.y))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Parses a WKT `POINT` or `MULTIPOINT` into a list of coordinates.
+///
+/// Both `MULTIPOINT ((0 0), (1 1))` and `MULTIPOINT (0 0, 1 1)` are accepted. Geometries with
+/// further dimensions are rejected.
+pub(super) fn multi_point_from_wkt(wkt: &str) -> Result<Vec<Coordinate2D>> {
+    let wkt = wkt.trim();
+    let tag_end = wkt
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(wkt.len());
+    let (tag, body) = wkt.split_at(tag_end);
+    let body = body.trim();
+
+    let is_point = tag.eq_ignore_ascii_case("POINT");
+    ensure!(
+        is_point || tag.eq_ignore_ascii_case("MULTIPOINT"),
+        error::Wkt {
+            details: format!("Unsupported geometry type `{}`", tag),
+        }
+    );
+
+    if body.eq_ignore_ascii_case("EMPTY") {
+        return Ok(Vec::new());
+    }
+
+    let body = parenthesized(body).ok_or_else(|| error::Error::Wkt {
+        details: format!("Invalid {} body `{}`", tag.to_ascii_uppercase(), body),
+    })?;
+
+    if is_point {
+        return Ok(vec![wkt_position(body)?]);
+    }
+
+    body.split(',')
+        .map(|point| {
+            let point = point.trim();
+            wkt_position(parenthesized(point).unwrap_or(point))
+        })
+        .collect()
+}
+
+fn parenthesized(text: &str) -> Option<&str> {
+    if text.starts_with('(') && text.ends_with(')') {
+        Some(text[1..text.len() - 1].trim())
+    } else {
+        None
+    }
+}
+
+fn wkt_position(position: &str) -> Result<Coordinate2D> {
+    let values = position
+        .split_whitespace()
+        .map(str::parse)
+        .collect::<std::result::Result<Vec<f64>, _>>()
+        .map_err(|_| error::Error::Wkt {
+            details: format!("Invalid position `{}`", position),
+        })?;
+
+    match values.as_slice() {
+        &[x, y] => Ok(Coordinate2D::new(x, y)),
+        _ => error::Wkt {
+            details: format!("Position `{}` must have exactly two dimensions", position),
+        }
+        .fail(),
+    }
+}
+
+/// Writes the coordinates of a feature as little endian WKB.
+///
+/// A single coordinate becomes a `Point`, all other coordinate lists a `MultiPoint`.
+pub(super) fn multi_point_to_wkb(coordinates: &[Coordinate2D]) -> Vec<u8> {
+    fn append_point(wkb: &mut Vec<u8>, coordinate: Coordinate2D) {
+        wkb.push(WKB_LITTLE_ENDIAN);
+        wkb.extend_from_slice(&WKB_POINT.to_le_bytes());
+        wkb.extend_from_slice(&coordinate.x.to_le_bytes());
+        wkb.extend_from_slice(&coordinate.y.to_le_bytes());
+    }
+
+    if let [coordinate] = coordinates {
+        let mut wkb = Vec::with_capacity(WKB_POINT_SIZE);
+        append_point(&mut wkb, *coordinate);
+        return wkb;
+    }
+
+    let mut wkb = Vec::with_capacity(9 + WKB_POINT_SIZE * coordinates.len());
+    wkb.push(WKB_LITTLE_ENDIAN);
+    wkb.extend_from_slice(&WKB_MULTI_POINT.to_le_bytes());
+    wkb.extend_from_slice(&(coordinates.len() as u32).to_le_bytes());
+    for coordinate in coordinates {
+        append_point(&mut wkb, *coordinate);
+    }
+    wkb
+}
+
+/// Parses a WKB `Point` or `MultiPoint` of either byte order into a list of coordinates.
+///
+/// PostGIS' extended WKB with an embedded SRID is accepted and the SRID is ignored.
+/// A `Point` with `NaN` coordinates is treated as empty.
+pub(super) fn multi_point_from_wkb(wkb: &[u8]) -> Result<Vec<Coordinate2D>> {
+    let mut reader = WkbReader {
+        wkb,
+        position: 0,
+        big_endian: false,
+    };
+
+    let coordinates = match reader.header()? {
+        WKB_POINT => reader.point()?.into_iter().collect(),
+        WKB_MULTI_POINT => {
+            let number_of_points = reader.u32()?;
+            // the count is untrusted, so only reserve what the remaining bytes can hold
+            let mut coordinates = Vec::with_capacity(
+                (number_of_points as usize).min((wkb.len() - reader.position) / WKB_POINT_SIZE),
+            );
+            for _ in 0..number_of_points {
+                ensure!(
+                    reader.header()? == WKB_POINT,
+                    error::Wkb {
+                        details: "A MultiPoint must only contain Points",
+                    }
+                );
+                coordinates.extend(reader.point()?);
+            }
+            coordinates
+        }
+        geometry_type => {
+            return error::Wkb {
+                details: format!("Unsupported geometry type {}", geometry_type),
+            }
+            .fail()
+        }
+    };
+
+    ensure!(
+        reader.position == wkb.len(),
+        error::Wkb {
+            details: "Trailing bytes after geometry",
+        }
+    );
+
+    Ok(coordinates)
+}
+
+struct WkbReader<'w> {
+    wkb: &'w [u8],
+    position: usize,
+    /// The byte order of the current geometry
+    big_endian: bool,
+}
+
+impl<'w> WkbReader<'w> {
+    fn bytes(&mut self, len: usize) -> Result<&'w [u8]> {
+        let bytes = self
+            .wkb
+            .get(self.position..self.position + len)
+            .ok_or_else(|| error::Error::Wkb {
+                details: "Unexpected end of input".to_string(),
+            })?;
+        self.position += len;
+        Ok(bytes)
+    }
+
+    /// Reads the byte order and geometry type and skips an embedded SRID
+    fn header(&mut self) -> Result<u32> {
+        self.big_endian = match self.bytes(1)?[0] {
+            WKB_BIG_ENDIAN => true,
+            WKB_LITTLE_ENDIAN => false,
+            byte_order => {
+                return error::Wkb {
+                    details: format!("Invalid byte order {}", byte_order),
+                }
+                .fail()
+            }
+        };
+
+        let geometry_type = self.u32()?;
+        if geometry_type & EWKB_SRID_FLAG != 0 {
+            self.u32()?;
+        }
+
+        Ok(geometry_type & !EWKB_SRID_FLAG)
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.bytes(4)?.try_into().expect("slice has length 4");
+        Ok(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64> {
+        let bytes = self.bytes(8)?.try_into().expect("slice has length 8");
+        Ok(if self.big_endian {
+            f64::from_be_bytes(bytes)
+        } else {
+            f64::from_le_bytes(bytes)
+        })
+    }
+
+    fn point(&mut self) -> Result<Option<Coordinate2D>> {
+        let x = self.f64()?;
+        let y = self.f64()?;
+
+        if x.is_nan() && y.is_nan() {
+            Ok(None)
+        } else {
+            Ok(Some(Coordinate2D::new(x, y)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn multi_point_count_exceeds_input() {
+        let mut wkb = vec![WKB_LITTLE_ENDIAN];
+        wkb.extend_from_slice(&WKB_MULTI_POINT.to_le_bytes());
+        wkb.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(multi_point_from_wkb(&wkb).is_err());
+
+        let mut wkb = multi_point_to_wkb(&[(0., 0.).into(), (1., 1.).into()]);
+        wkb[5..9].copy_from_slice(&3_u32.to_le_bytes());
+        assert!(multi_point_from_wkb(&wkb).is_err());
+
+        wkb.truncate(wkb.len() - 1);
+        wkb[5..9].copy_from_slice(&2_u32.to_le_bytes());
+        assert!(multi_point_from_wkb(&wkb).is_err());
+    }
+}
@@ -1,7 +1,7 @@
 use arrow::error::ArrowError;
 use snafu::Snafu;
 
-use crate::primitives::TimeInterval;
+use crate::primitives::{Coordinate2D, TimeInterval};
 
 #[derive(Debug, PartialEq, Snafu)]
 #[snafu(visibility = "pub(crate)")]
@@ -27,6 +27,16 @@ pub enum Error {
     ))]
     TimeIntervalUnmatchedIntervals { i1: TimeInterval, i2: TimeInterval },
 
+    #[snafu(display(
+        "Lower left `{:?}` must be below and left of upper right `{:?}`",
+        lower_left,
+        upper_right
+    ))]
+    InvalidBoundingBox {
+        lower_left: Coordinate2D,
+        upper_right: Coordinate2D,
+    },
+
     #[snafu(display(
         "Mask length ≠ collection length ({} ≠ {})",
         mask_length,
@@ -48,6 +58,28 @@ pub enum Error {
 
     #[snafu(display("Plot exception: {}", details))]
     PlotError { details: String },
+
+    #[snafu(display("GeoJSON exception: {}", details))]
+    GeoJson { details: String },
+
+    #[snafu(display("Unsupported GeoJSON geometry type: {}", geometry_type))]
+    GeoJsonUnsupportedGeometry { geometry_type: String },
+
+    #[snafu(display("CSV exception: {}", details))]
+    Csv { details: String },
+
+    #[snafu(display("CSV parse error in line {}, column {}: {}", row, column, details))]
+    CsvParse {
+        row: u64,
+        column: usize,
+        details: String,
+    },
+
+    #[snafu(display("WKT exception: {}", details))]
+    Wkt { details: String },
+
+    #[snafu(display("WKB exception: {}", details))]
+    Wkb { details: String },
 }
 
 impl From<arrow::error::ArrowError> for Error {
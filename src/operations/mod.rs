@@ -1,3 +1,5 @@
 mod filterable;
+mod takeable;
 
 pub use filterable::Filterable;
+pub use takeable::Takeable;
@@ -0,0 +1,12 @@
+use crate::util::Result;
+
+/// Adds index-based selection functionality to a feature_collection.
+pub trait Takeable: Sized {
+    /// Creates a new feature_collection from the features at `indices` in the given order.
+    /// Indices may occur multiple times.
+    fn take(&self, indices: &[usize]) -> Result<Self>;
+
+    /// Creates a new feature_collection of the `len` features starting at `offset`
+    /// that shares the buffers of this feature_collection instead of copying them
+    fn slice(&self, offset: usize, len: usize) -> Result<Self>;
+}
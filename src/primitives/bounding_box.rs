@@ -0,0 +1,78 @@
+use crate::error;
+use crate::primitives::Coordinate2D;
+use crate::util::Result;
+use serde::{Deserialize, Serialize};
+use snafu::ensure;
+
+/// An axis-aligned rectangle, including its boundary
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BoundingBox2D {
+    lower_left: Coordinate2D,
+    upper_right: Coordinate2D,
+}
+
+impl BoundingBox2D {
+    /// Creates a new bounding box and checks that `lower_left` is not above or right of `upper_right`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoengine_datatypes::primitives::BoundingBox2D;
+    ///
+    /// BoundingBox2D::new((0., 0.).into(), (1., 1.).into()).unwrap();
+    /// BoundingBox2D::new((0., 0.).into(), (0., 0.).into()).unwrap();
+    ///
+    /// BoundingBox2D::new((1., 0.).into(), (0., 1.).into()).unwrap_err();
+    /// ```
+    ///
+    pub fn new(lower_left: Coordinate2D, upper_right: Coordinate2D) -> Result<Self> {
+        ensure!(
+            lower_left.x <= upper_right.x && lower_left.y <= upper_right.y,
+            error::InvalidBoundingBox {
+                lower_left,
+                upper_right,
+            }
+        );
+        Ok(Self {
+            lower_left,
+            upper_right,
+        })
+    }
+
+    /// Creates a new bounding box without bound checks
+    pub fn new_unchecked(lower_left: Coordinate2D, upper_right: Coordinate2D) -> Self {
+        Self {
+            lower_left,
+            upper_right,
+        }
+    }
+
+    pub fn lower_left(&self) -> Coordinate2D {
+        self.lower_left
+    }
+
+    pub fn upper_right(&self) -> Coordinate2D {
+        self.upper_right
+    }
+
+    /// Returns whether the coordinate lies inside the bounding box or on its boundary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geoengine_datatypes::primitives::{BoundingBox2D, Coordinate2D};
+    ///
+    /// let bbox = BoundingBox2D::new((0., 0.).into(), (1., 1.).into()).unwrap();
+    ///
+    /// assert!(bbox.contains_coordinate(&(0.5, 0.5).into()));
+    /// assert!(bbox.contains_coordinate(&(1., 0.).into()));
+    /// assert!(!bbox.contains_coordinate(&(1.5, 0.5).into()));
+    /// ```
+    ///
+    pub fn contains_coordinate(&self, coordinate: &Coordinate2D) -> bool {
+        coordinate.x >= self.lower_left.x
+            && coordinate.y >= self.lower_left.y
+            && coordinate.x <= self.upper_right.x
+            && coordinate.y <= self.upper_right.y
+    }
+}
@@ -1,11 +1,14 @@
 use crate::error;
 use crate::util::Result;
 use arrow::bitmap::Bitmap;
+use arrow::util::bit_util;
+use serde::{Deserialize, Serialize};
 use snafu::ensure;
+use std::mem;
 use std::slice;
 use std::str;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub enum FeatureDataType {
     Text,
     NullableText,
@@ -15,9 +18,11 @@ pub enum FeatureDataType {
     NullableDecimal,
     Categorical,
     NullableCategorical,
+    Bool,
+    NullableBool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum FeatureData {
     Text(Vec<String>),
     NullableText(Vec<Option<String>>),
@@ -27,9 +32,11 @@ pub enum FeatureData {
     NullableDecimal(Vec<Option<i64>>),
     Categorical(Vec<u8>), // TODO: add names to categories
     NullableCategorical(Vec<Option<u8>>),
+    Bool(Vec<bool>),
+    NullableBool(Vec<Option<bool>>),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum FeatureDataValue {
     Text(String),
     NullableText(Option<String>),
@@ -39,6 +46,8 @@ pub enum FeatureDataValue {
     NullableDecimal(Option<i64>),
     Categorical(u8),
     NullableCategorical(Option<u8>),
+    Bool(bool),
+    NullableBool(Option<bool>),
 }
 
 #[derive(Clone, Debug)]
@@ -51,6 +60,8 @@ pub enum FeatureDataRef<'f> {
     NullableDecimal(NullableDecimalDataRef<'f>),
     Categorical(CategoricalDataRef),
     NullableCategorical(NullableCategoricalDataRef<'f>),
+    Bool(BoolDataRef),
+    NullableBool(NullableBoolDataRef<'f>),
 }
 
 pub trait NullableDataRef {
@@ -60,11 +71,13 @@ pub trait NullableDataRef {
 #[derive(Clone, Debug)]
 pub struct NumberDataRef {
     buffer: arrow::buffer::Buffer,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[f64]> for NumberDataRef {
     fn as_ref(&self) -> &[f64] {
-        self.buffer.typed_data()
+        &self.buffer.typed_data()[self.offset..self.offset + self.len]
     }
 }
 
@@ -76,7 +89,17 @@ impl From<NumberDataRef> for FeatureDataRef<'_> {
 
 impl NumberDataRef {
     pub fn new(buffer: arrow::buffer::Buffer) -> Self {
-        Self { buffer }
+        let len = buffer.len() / mem::size_of::<f64>();
+        Self::new_with_offset(buffer, 0, len)
+    }
+
+    /// Refers to the `len` values of `buffer` that start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(buffer: arrow::buffer::Buffer, offset: usize, len: usize) -> Self {
+        Self {
+            buffer,
+            offset,
+            len,
+        }
     }
 }
 
@@ -84,17 +107,19 @@ impl NumberDataRef {
 pub struct NullableNumberDataRef<'f> {
     buffer: arrow::buffer::Buffer,
     null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[f64]> for NullableNumberDataRef<'_> {
     fn as_ref(&self) -> &[f64] {
-        self.buffer.typed_data()
+        &self.buffer.typed_data()[self.offset..self.offset + self.len]
     }
 }
 
 impl<'f> NullableDataRef for NullableNumberDataRef<'f> {
     fn nulls(&self) -> Vec<bool> {
-        null_bitmap_to_bools(self.as_ref(), self.null_bitmap)
+        null_bitmap_to_bools(self.null_bitmap, self.offset, self.len)
     }
 }
 
@@ -108,10 +133,23 @@ impl<'f> NullableNumberDataRef<'f> {
     pub fn new(
         buffer: arrow::buffer::Buffer,
         null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    ) -> Self {
+        let len = buffer.len() / mem::size_of::<f64>();
+        Self::new_with_offset(buffer, null_bitmap, 0, len)
+    }
+
+    /// Refers to the `len` values of `buffer` and bits of `null_bitmap` that start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(
+        buffer: arrow::buffer::Buffer,
+        null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+        offset: usize,
+        len: usize,
     ) -> Self {
         Self {
             buffer,
             null_bitmap,
+            offset,
+            len,
         }
     }
 }
@@ -119,11 +157,13 @@ impl<'f> NullableNumberDataRef<'f> {
 #[derive(Clone, Debug)]
 pub struct DecimalDataRef {
     buffer: arrow::buffer::Buffer,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[i64]> for DecimalDataRef {
     fn as_ref(&self) -> &[i64] {
-        self.buffer.typed_data()
+        &self.buffer.typed_data()[self.offset..self.offset + self.len]
     }
 }
 
@@ -135,7 +175,17 @@ impl From<DecimalDataRef> for FeatureDataRef<'_> {
 
 impl DecimalDataRef {
     pub fn new(buffer: arrow::buffer::Buffer) -> Self {
-        Self { buffer }
+        let len = buffer.len() / mem::size_of::<i64>();
+        Self::new_with_offset(buffer, 0, len)
+    }
+
+    /// Refers to the `len` values of `buffer` that start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(buffer: arrow::buffer::Buffer, offset: usize, len: usize) -> Self {
+        Self {
+            buffer,
+            offset,
+            len,
+        }
     }
 }
 
@@ -143,25 +193,27 @@ impl DecimalDataRef {
 pub struct NullableDecimalDataRef<'f> {
     buffer: arrow::buffer::Buffer,
     null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[i64]> for NullableDecimalDataRef<'_> {
     fn as_ref(&self) -> &[i64] {
-        self.buffer.typed_data()
+        &self.buffer.typed_data()[self.offset..self.offset + self.len]
     }
 }
 
 impl<'f> NullableDataRef for NullableDecimalDataRef<'f> {
     fn nulls(&self) -> Vec<bool> {
-        null_bitmap_to_bools(self.as_ref(), self.null_bitmap)
+        null_bitmap_to_bools(self.null_bitmap, self.offset, self.len)
     }
 }
 
-fn null_bitmap_to_bools<T>(data: &[T], null_bitmap: &Option<Bitmap>) -> Vec<bool> {
+fn null_bitmap_to_bools(null_bitmap: &Option<Bitmap>, offset: usize, len: usize) -> Vec<bool> {
     if let Some(nulls) = null_bitmap {
-        (0..data.len()).map(|i| !nulls.is_set(i)).collect()
+        (0..len).map(|i| !nulls.is_set(offset + i)).collect()
     } else {
-        vec![false; data.len()]
+        vec![false; len]
     }
 }
 
@@ -175,10 +227,23 @@ impl<'f> NullableDecimalDataRef<'f> {
     pub fn new(
         buffer: arrow::buffer::Buffer,
         null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    ) -> Self {
+        let len = buffer.len() / mem::size_of::<i64>();
+        Self::new_with_offset(buffer, null_bitmap, 0, len)
+    }
+
+    /// Refers to the `len` values of `buffer` and bits of `null_bitmap` that start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(
+        buffer: arrow::buffer::Buffer,
+        null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+        offset: usize,
+        len: usize,
     ) -> Self {
         Self {
             buffer,
             null_bitmap,
+            offset,
+            len,
         }
     }
 }
@@ -186,11 +251,13 @@ impl<'f> NullableDecimalDataRef<'f> {
 #[derive(Clone, Debug)]
 pub struct CategoricalDataRef {
     buffer: arrow::buffer::Buffer,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[u8]> for CategoricalDataRef {
     fn as_ref(&self) -> &[u8] {
-        self.buffer.typed_data()
+        &self.buffer.typed_data()[self.offset..self.offset + self.len]
     }
 }
 
@@ -202,7 +269,17 @@ impl From<CategoricalDataRef> for FeatureDataRef<'_> {
 
 impl CategoricalDataRef {
     pub fn new(buffer: arrow::buffer::Buffer) -> Self {
-        Self { buffer }
+        let len = buffer.len() / mem::size_of::<u8>();
+        Self::new_with_offset(buffer, 0, len)
+    }
+
+    /// Refers to the `len` values of `buffer` that start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(buffer: arrow::buffer::Buffer, offset: usize, len: usize) -> Self {
+        Self {
+            buffer,
+            offset,
+            len,
+        }
     }
 }
 
@@ -210,17 +287,19 @@ impl CategoricalDataRef {
 pub struct NullableCategoricalDataRef<'f> {
     buffer: arrow::buffer::Buffer,
     null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[u8]> for NullableCategoricalDataRef<'_> {
     fn as_ref(&self) -> &[u8] {
-        self.buffer.typed_data()
+        &self.buffer.typed_data()[self.offset..self.offset + self.len]
     }
 }
 
 impl<'f> NullableDataRef for NullableCategoricalDataRef<'f> {
     fn nulls(&self) -> Vec<bool> {
-        null_bitmap_to_bools(self.as_ref(), self.null_bitmap)
+        null_bitmap_to_bools(self.null_bitmap, self.offset, self.len)
     }
 }
 
@@ -234,12 +313,180 @@ impl<'f> NullableCategoricalDataRef<'f> {
     pub fn new(
         buffer: arrow::buffer::Buffer,
         null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    ) -> Self {
+        let len = buffer.len() / mem::size_of::<u8>();
+        Self::new_with_offset(buffer, null_bitmap, 0, len)
+    }
+
+    /// Refers to the `len` values of `buffer` and bits of `null_bitmap` that start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(
+        buffer: arrow::buffer::Buffer,
+        null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+        offset: usize,
+        len: usize,
+    ) -> Self {
+        Self {
+            buffer,
+            null_bitmap,
+            offset,
+            len,
+        }
+    }
+}
+
+/// A reference to bool data, which arrow packs into a bitmap
+///
+/// # Examples
+///
+/// ```rust
+/// use geoengine_datatypes::primitives::BoolDataRef;
+/// use arrow::array::{Array, BooleanArray};
+///
+/// let array = BooleanArray::from(vec![true, false, true]);
+/// let bool_data_ref = BoolDataRef::new(array.values(), array.offset(), array.len());
+///
+/// assert_eq!(bool_data_ref.len(), 3);
+/// assert_eq!(bool_data_ref.value_at(2), Ok(true));
+/// assert!(bool_data_ref.value_at(3).is_err());
+/// assert_eq!(bool_data_ref.to_mask(), vec![true, false, true]);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct BoolDataRef {
+    buffer: arrow::buffer::Buffer,
+    offset: usize,
+    len: usize,
+}
+
+impl From<BoolDataRef> for FeatureDataRef<'_> {
+    fn from(data_ref: BoolDataRef) -> Self {
+        Self::Bool(data_ref)
+    }
+}
+
+impl BoolDataRef {
+    /// Refers to the `len` bits of `buffer` that start at `offset`
+    pub fn new(buffer: arrow::buffer::Buffer, offset: usize, len: usize) -> Self {
+        Self {
+            buffer,
+            offset,
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn value_at(&self, pos: usize) -> Result<bool> {
+        bit_at(&self.buffer, self.offset, self.len, pos)
+    }
+
+    /// Unpacks the values, e.g., for filtering a collection
+    pub fn to_mask(&self) -> Vec<bool> {
+        (0..self.len)
+            .map(|i| bit_util::get_bit(self.buffer.data(), self.offset + i))
+            .collect()
+    }
+}
+
+/// A reference to nullable bool data, which arrow packs into a bitmap
+///
+/// # Examples
+///
+/// ```rust
+/// use geoengine_datatypes::primitives::{NullableBoolDataRef, NullableDataRef};
+/// use arrow::array::{Array, BooleanArray};
+///
+/// let array = BooleanArray::from(vec![Some(true), None, Some(false)]);
+/// let bool_data_ref = NullableBoolDataRef::new(
+///     array.values(),
+///     array.data_ref().null_bitmap(),
+///     array.offset(),
+///     array.len(),
+/// );
+///
+/// assert_eq!(bool_data_ref.value_at(0), Ok(Some(true)));
+/// assert_eq!(bool_data_ref.value_at(1), Ok(None));
+/// assert_eq!(bool_data_ref.nulls(), vec![false, true, false]);
+/// assert_eq!(bool_data_ref.to_mask(), vec![true, false, false]);
+/// ```
+///
+#[derive(Clone, Debug)]
+pub struct NullableBoolDataRef<'f> {
+    buffer: arrow::buffer::Buffer,
+    null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+    offset: usize,
+    len: usize,
+}
+
+impl<'f> NullableDataRef for NullableBoolDataRef<'f> {
+    fn nulls(&self) -> Vec<bool> {
+        null_bitmap_to_bools(self.null_bitmap, self.offset, self.len)
+    }
+}
+
+impl<'f> From<NullableBoolDataRef<'f>> for FeatureDataRef<'f> {
+    fn from(data_ref: NullableBoolDataRef<'f>) -> Self {
+        Self::NullableBool(data_ref)
+    }
+}
+
+impl<'f> NullableBoolDataRef<'f> {
+    /// Refers to the `len` bits of `buffer` and `null_bitmap` that start at `offset`
+    pub fn new(
+        buffer: arrow::buffer::Buffer,
+        null_bitmap: &'f Option<arrow::bitmap::Bitmap>,
+        offset: usize,
+        len: usize,
     ) -> Self {
         Self {
             buffer,
             null_bitmap,
+            offset,
+            len,
         }
     }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn value_at(&self, pos: usize) -> Result<Option<bool>> {
+        let value = bit_at(&self.buffer, self.offset, self.len, pos)?;
+        Ok(match self.null_bitmap {
+            Some(null_bitmap) if !null_bitmap.is_set(self.offset + pos) => None,
+            _ => Some(value),
+        })
+    }
+
+    /// Unpacks the values, where nulls are `false`, e.g., for filtering a collection
+    pub fn to_mask(&self) -> Vec<bool> {
+        self.nulls()
+            .into_iter()
+            .enumerate()
+            .map(|(i, is_null)| !is_null && bit_util::get_bit(self.buffer.data(), self.offset + i))
+            .collect()
+    }
+}
+
+fn bit_at(buffer: &arrow::buffer::Buffer, offset: usize, len: usize, pos: usize) -> Result<bool> {
+    ensure!(
+        pos < len,
+        error::FeatureData {
+            details: "Position must be in data range"
+        }
+    );
+
+    Ok(bit_util::get_bit(buffer.data(), offset + pos))
 }
 
 /// A reference to text data
@@ -275,6 +522,8 @@ impl<'f> NullableCategoricalDataRef<'f> {
 pub struct TextDataRef {
     data_buffer: arrow::buffer::Buffer,
     offsets_buffer: arrow::buffer::Buffer,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[u8]> for TextDataRef {
@@ -291,19 +540,36 @@ impl From<TextDataRef> for FeatureDataRef<'_> {
 
 impl TextDataRef {
     pub fn new(data_buffer: arrow::buffer::Buffer, offsets_buffer: arrow::buffer::Buffer) -> Self {
+        let len = (offsets_buffer.len() / mem::size_of::<i32>()).saturating_sub(1);
+        Self::new_with_offset(data_buffer, offsets_buffer, 0, len)
+    }
+
+    /// Refers to the `len` texts whose offsets start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(
+        data_buffer: arrow::buffer::Buffer,
+        offsets_buffer: arrow::buffer::Buffer,
+        offset: usize,
+        len: usize,
+    ) -> Self {
         Self {
             data_buffer,
             offsets_buffer,
+            offset,
+            len,
         }
     }
 
+    /// The `len() + 1` offsets of the texts into the data buffer
     pub fn offsets(&self) -> &[i32] {
-        self.offsets_buffer.typed_data()
+        self.offsets_buffer
+            .typed_data()
+            .get(self.offset..=self.offset + self.len)
+            .unwrap_or(&[])
     }
 
     pub fn text_at(&self, pos: usize) -> Result<&str> {
         ensure!(
-            pos < (self.offsets().len() - 1),
+            pos < self.len,
             error::FeatureData {
                 details: "Position must be in data range"
             }
@@ -314,7 +580,7 @@ impl TextDataRef {
 
         let text = unsafe {
             byte_ptr_to_str(
-                self.data_buffer.slice(start as usize).raw_data(),
+                self.data_buffer.data()[start as usize..].as_ptr(),
                 (end - start) as usize,
             )
         };
@@ -361,6 +627,8 @@ unsafe fn byte_ptr_to_str<'d>(bytes: *const u8, length: usize) -> &'d str {
 pub struct NullableTextDataRef {
     data_buffer: arrow::buffer::Buffer,
     offsets_buffer: arrow::buffer::Buffer,
+    offset: usize,
+    len: usize,
 }
 
 impl AsRef<[u8]> for NullableTextDataRef {
@@ -377,19 +645,36 @@ impl From<NullableTextDataRef> for FeatureDataRef<'_> {
 
 impl NullableTextDataRef {
     pub fn new(data_buffer: arrow::buffer::Buffer, offsets_buffer: arrow::buffer::Buffer) -> Self {
+        let len = (offsets_buffer.len() / mem::size_of::<i32>()).saturating_sub(1);
+        Self::new_with_offset(data_buffer, offsets_buffer, 0, len)
+    }
+
+    /// Refers to the `len` texts whose offsets start at `offset`, e.g., of a sliced array
+    pub fn new_with_offset(
+        data_buffer: arrow::buffer::Buffer,
+        offsets_buffer: arrow::buffer::Buffer,
+        offset: usize,
+        len: usize,
+    ) -> Self {
         Self {
             data_buffer,
             offsets_buffer,
+            offset,
+            len,
         }
     }
 
+    /// The `len() + 1` offsets of the texts into the data buffer
     pub fn offsets(&self) -> &[i32] {
-        self.offsets_buffer.typed_data()
+        self.offsets_buffer
+            .typed_data()
+            .get(self.offset..=self.offset + self.len)
+            .unwrap_or(&[])
     }
 
     pub fn text_at(&self, pos: usize) -> Result<Option<&str>> {
         ensure!(
-            pos < (self.offsets().len() - 1),
+            pos < self.len,
             error::FeatureData {
                 details: "Position must be in data range"
             }
@@ -404,7 +689,7 @@ impl NullableTextDataRef {
 
         let text = unsafe {
             byte_ptr_to_str(
-                self.data_buffer.slice(start as usize).raw_data(),
+                self.data_buffer.data()[start as usize..].as_ptr(),
                 (end - start) as usize,
             )
         };
@@ -438,7 +723,7 @@ impl NullableDataRef for NullableTextDataRef {
     /// ```
     ///
     fn nulls(&self) -> Vec<bool> {
-        let mut nulls = Vec::with_capacity(self.offsets().len() - 1);
+        let mut nulls = Vec::with_capacity(self.len);
         for window in self.offsets().windows(2) {
             let (start, end) = (window[0], window[1]);
             nulls.push(start == end);
@@ -447,6 +732,100 @@ impl NullableDataRef for NullableTextDataRef {
     }
 }
 
+impl FeatureDataRef<'_> {
+    /// Retrieve the value at position `pos` without copying the column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::primitives::{FeatureDataRef, FeatureDataValue, NullableNumberDataRef};
+    /// use arrow::array::{Array, Float64Array};
+    ///
+    /// let array = Float64Array::from(vec![Some(1.), None]);
+    /// let data_ref: FeatureDataRef = NullableNumberDataRef::new(array.values(), array.data_ref().null_bitmap()).into();
+    ///
+    /// if let FeatureDataValue::NullableNumber(value) = data_ref.get(0).unwrap() {
+    ///     assert_eq!(value, Some(1.));
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// if let FeatureDataValue::NullableNumber(value) = data_ref.get(1).unwrap() {
+    ///     assert_eq!(value, None);
+    /// } else {
+    ///     unreachable!();
+    /// }
+    /// ```
+    pub fn get(&self, pos: usize) -> Result<FeatureDataValue> {
+        fn value_at<T: Copy>(values: &[T], pos: usize) -> Result<T> {
+            values
+                .get(pos)
+                .copied()
+                .ok_or_else(|| error::Error::FeatureData {
+                    details: "Position must be in data range".to_string(),
+                })
+        }
+
+        fn nullable_value_at<T: Copy>(
+            values: &[T],
+            null_bitmap: &Option<Bitmap>,
+            offset: usize,
+            pos: usize,
+        ) -> Result<Option<T>> {
+            let value = value_at(values, pos)?;
+            Ok(match null_bitmap {
+                Some(null_bitmap) if !null_bitmap.is_set(offset + pos) => None,
+                _ => Some(value),
+            })
+        }
+
+        Ok(match self {
+            FeatureDataRef::Text(data_ref) => {
+                FeatureDataValue::Text(data_ref.text_at(pos)?.to_string())
+            }
+            FeatureDataRef::NullableText(data_ref) => {
+                FeatureDataValue::NullableText(data_ref.text_at(pos)?.map(str::to_string))
+            }
+            FeatureDataRef::Number(data_ref) => {
+                FeatureDataValue::Number(value_at(data_ref.as_ref(), pos)?)
+            }
+            FeatureDataRef::NullableNumber(data_ref) => {
+                FeatureDataValue::NullableNumber(nullable_value_at(
+                    data_ref.as_ref(),
+                    data_ref.null_bitmap,
+                    data_ref.offset,
+                    pos,
+                )?)
+            }
+            FeatureDataRef::Decimal(data_ref) => {
+                FeatureDataValue::Decimal(value_at(data_ref.as_ref(), pos)?)
+            }
+            FeatureDataRef::NullableDecimal(data_ref) => {
+                FeatureDataValue::NullableDecimal(nullable_value_at(
+                    data_ref.as_ref(),
+                    data_ref.null_bitmap,
+                    data_ref.offset,
+                    pos,
+                )?)
+            }
+            FeatureDataRef::Categorical(data_ref) => {
+                FeatureDataValue::Categorical(value_at(data_ref.as_ref(), pos)?)
+            }
+            FeatureDataRef::NullableCategorical(data_ref) => {
+                FeatureDataValue::NullableCategorical(nullable_value_at(
+                    data_ref.as_ref(),
+                    data_ref.null_bitmap,
+                    data_ref.offset,
+                    pos,
+                )?)
+            }
+            FeatureDataRef::Bool(data_ref) => FeatureDataValue::Bool(data_ref.value_at(pos)?),
+            FeatureDataRef::NullableBool(data_ref) => {
+                FeatureDataValue::NullableBool(data_ref.value_at(pos)?)
+            }
+        })
+    }
+}
+
 impl FeatureDataType {
     pub fn arrow_data_type(self) -> arrow::datatypes::DataType {
         match self {
@@ -454,19 +833,63 @@ impl FeatureDataType {
             Self::Number | Self::NullableNumber => arrow::datatypes::DataType::Float64,
             Self::Decimal | Self::NullableDecimal => arrow::datatypes::DataType::Int64,
             Self::Categorical | Self::NullableCategorical => arrow::datatypes::DataType::UInt8,
+            Self::Bool | Self::NullableBool => arrow::datatypes::DataType::Boolean,
         }
     }
 
     pub fn nullable(self) -> bool {
         match self {
-            Self::Text | Self::Number | Self::Decimal | Self::Categorical => false,
+            Self::Text | Self::Number | Self::Decimal | Self::Categorical | Self::Bool => false,
             Self::NullableText
             | Self::NullableNumber
             | Self::NullableDecimal
-            | Self::NullableCategorical => true,
+            | Self::NullableCategorical
+            | Self::NullableBool => true,
         }
     }
 
+    /// Derive the feature data type from an arrow data type and its nullability
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::primitives::FeatureDataType;
+    /// use arrow::datatypes::DataType;
+    ///
+    /// assert_eq!(
+    ///     FeatureDataType::from_arrow_data_type(&DataType::Utf8, true).unwrap(),
+    ///     FeatureDataType::NullableText
+    /// );
+    /// assert_eq!(
+    ///     FeatureDataType::from_arrow_data_type(&DataType::Float64, false).unwrap(),
+    ///     FeatureDataType::Number
+    /// );
+    /// assert!(FeatureDataType::from_arrow_data_type(&DataType::Int8, false).is_err());
+    /// ```
+    pub fn from_arrow_data_type(
+        data_type: &arrow::datatypes::DataType,
+        nullable: bool,
+    ) -> Result<Self> {
+        Ok(match (data_type, nullable) {
+            (arrow::datatypes::DataType::Utf8, false) => Self::Text,
+            (arrow::datatypes::DataType::Utf8, true) => Self::NullableText,
+            (arrow::datatypes::DataType::Float64, false) => Self::Number,
+            (arrow::datatypes::DataType::Float64, true) => Self::NullableNumber,
+            (arrow::datatypes::DataType::Int64, false) => Self::Decimal,
+            (arrow::datatypes::DataType::Int64, true) => Self::NullableDecimal,
+            (arrow::datatypes::DataType::UInt8, false) => Self::Categorical,
+            (arrow::datatypes::DataType::UInt8, true) => Self::NullableCategorical,
+            (arrow::datatypes::DataType::Boolean, false) => Self::Bool,
+            (arrow::datatypes::DataType::Boolean, true) => Self::NullableBool,
+            (data_type, _) => {
+                return error::FeatureData {
+                    details: format!("Unsupported arrow data type {:?}", data_type),
+                }
+                .fail()
+            }
+        })
+    }
+
     pub fn arrow_builder(self, len: usize) -> Box<dyn arrow::array::ArrayBuilder> {
         match self {
             Self::Text | Self::NullableText => Box::new(arrow::array::StringBuilder::new(len)),
@@ -475,6 +898,7 @@ impl FeatureDataType {
             Self::Categorical | Self::NullableCategorical => {
                 Box::new(arrow::array::UInt8Builder::new(len))
             }
+            Self::Bool | Self::NullableBool => Box::new(arrow::array::BooleanBuilder::new(len)),
         }
     }
 }
@@ -498,6 +922,8 @@ impl FeatureData {
             FeatureData::NullableDecimal(v) => v.len(),
             FeatureData::Categorical(v) => v.len(),
             FeatureData::NullableCategorical(v) => v.len(),
+            FeatureData::Bool(v) => v.len(),
+            FeatureData::NullableBool(v) => v.len(),
         }
     }
 
@@ -505,6 +931,47 @@ impl FeatureData {
         self.len() == 0
     }
 
+    /// Returns the number of bytes of this column in arrow's memory layout, i.e., the values,
+    /// the offsets and bytes of texts and the validity bitmap of nullable columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use geoengine_datatypes::primitives::FeatureData;
+    ///
+    /// assert_eq!(FeatureData::Number(vec![1., 2.]).byte_size(), 16);
+    /// assert_eq!(FeatureData::NullableNumber(vec![Some(1.), None]).byte_size(), 16 + 1);
+    /// assert_eq!(FeatureData::Text(vec!["foo".into()]).byte_size(), 2 * 4 + 3);
+    /// assert_eq!(FeatureData::Bool(vec![true; 9]).byte_size(), 2);
+    /// ```
+    pub fn byte_size(&self) -> usize {
+        fn text_bytes<'t>(texts: impl Iterator<Item = &'t String>) -> usize {
+            texts.map(String::len).sum()
+        }
+
+        let len = self.len();
+        let text_offsets = (len + 1) * mem::size_of::<i32>();
+
+        let (values, nullable) = match self {
+            FeatureData::Text(v) => (text_offsets + text_bytes(v.iter()), false),
+            FeatureData::NullableText(v) => (text_offsets + text_bytes(v.iter().flatten()), true),
+            FeatureData::Number(_) => (len * mem::size_of::<f64>(), false),
+            FeatureData::NullableNumber(_) => (len * mem::size_of::<f64>(), true),
+            FeatureData::Decimal(_) => (len * mem::size_of::<i64>(), false),
+            FeatureData::NullableDecimal(_) => (len * mem::size_of::<i64>(), true),
+            FeatureData::Categorical(_) => (len * mem::size_of::<u8>(), false),
+            FeatureData::NullableCategorical(_) => (len * mem::size_of::<u8>(), true),
+            FeatureData::Bool(_) => (bit_util::ceil(len, 8), false),
+            FeatureData::NullableBool(_) => (bit_util::ceil(len, 8), true),
+        };
+
+        if nullable {
+            values + bit_util::ceil(len, 8)
+        } else {
+            values
+        }
+    }
+
     pub fn arrow_builder(&self) -> Result<Box<dyn arrow::array::ArrayBuilder>> {
         Ok(match self {
             Self::Text(v) => {
@@ -561,6 +1028,18 @@ impl FeatureData {
                 }
                 Box::new(builder)
             }
+            Self::Bool(v) => {
+                let mut builder = arrow::array::BooleanBuilder::new(v.len());
+                builder.append_slice(v)?;
+                Box::new(builder)
+            }
+            Self::NullableBool(v) => {
+                let mut builder = arrow::array::BooleanBuilder::new(v.len());
+                for &bool_option in v {
+                    builder.append_option(bool_option)?;
+                }
+                Box::new(builder)
+            }
         })
     }
 }
@@ -576,6 +1055,8 @@ impl From<&FeatureData> for FeatureDataType {
             FeatureData::NullableDecimal(_) => Self::NullableDecimal,
             FeatureData::Categorical(_) => Self::Categorical,
             FeatureData::NullableCategorical(_) => Self::NullableCategorical,
+            FeatureData::Bool(_) => Self::Bool,
+            FeatureData::NullableBool(_) => Self::NullableBool,
         }
     }
 }
@@ -591,6 +1072,8 @@ impl From<&FeatureDataValue> for FeatureDataType {
             FeatureDataValue::NullableDecimal(_) => Self::NullableDecimal,
             FeatureDataValue::Categorical(_) => Self::Categorical,
             FeatureDataValue::NullableCategorical(_) => Self::NullableCategorical,
+            FeatureDataValue::Bool(_) => Self::Bool,
+            FeatureDataValue::NullableBool(_) => Self::NullableBool,
         }
     }
 }
@@ -606,6 +1089,8 @@ impl<'f> From<&'f FeatureDataRef<'f>> for FeatureDataType {
             FeatureDataRef::NullableDecimal(_) => Self::NullableDecimal,
             FeatureDataRef::Categorical(_) => Self::Categorical,
             FeatureDataRef::NullableCategorical(_) => Self::NullableCategorical,
+            FeatureDataRef::Bool(_) => Self::Bool,
+            FeatureDataRef::NullableBool(_) => Self::NullableBool,
         }
     }
 }
@@ -1,13 +1,15 @@
+mod bounding_box;
 mod coordinate;
 mod feature_data;
 mod measurement;
 mod time_interval;
 
+pub use bounding_box::BoundingBox2D;
 pub use coordinate::Coordinate2D;
 pub use feature_data::{
-    CategoricalDataRef, DecimalDataRef, FeatureData, FeatureDataRef, FeatureDataType,
-    FeatureDataValue, NullableCategoricalDataRef, NullableDataRef, NullableDecimalDataRef,
-    NullableNumberDataRef, NullableTextDataRef, NumberDataRef, TextDataRef,
+    BoolDataRef, CategoricalDataRef, DecimalDataRef, FeatureData, FeatureDataRef, FeatureDataType,
+    FeatureDataValue, NullableBoolDataRef, NullableCategoricalDataRef, NullableDataRef,
+    NullableDecimalDataRef, NullableNumberDataRef, NullableTextDataRef, NumberDataRef, TextDataRef,
 };
 pub use measurement::Measurement;
 pub use time_interval::TimeInterval;